@@ -1,5 +1,5 @@
-use std::str::FromStr;
-use crate::{utils::add_sanitized_numeric_string, CharType, MatchOccurrences, ToSegments};
+use std::{borrow::Cow, str::FromStr};
+use crate::{char_type::{is_emoji, is_emoji_component}, diacritics::{fold_for_match, strip_diacritics}, utils::{accounting_negatives_to_signed, add_sanitized_numeric_string, trailing_minus_to_signed}, CharType, FromStrRadix, MatchOccurrences, NumberFormat, ToSegments};
 
 // Set of traits with extension methods to match core alphanumeric, numeric character patterns with words
 // ascertain if strings contain valid numbers and extract numbers as floats or integers
@@ -72,6 +72,10 @@ pub trait StripCharacters<'a> where Self:ToSegments {
   /// Remove characters in the specified character category/range
   fn strip_by_type(&self, ct: CharType<'a>) -> String;
 
+  /// Remove all characters in the specified category or range and count how many were removed,
+  /// e.g. for validation feedback such as "removed 3 invalid characters"
+  fn strip_by_type_counted(&self, ct: CharType<'a>) -> (String, usize);
+
   /// Remove characters in the specified range or type. Lets you exclude by a set of character types (as an array)
   fn strip_by_types(&self, cts: &[CharType<'a>]) -> String;
 
@@ -81,6 +85,50 @@ pub trait StripCharacters<'a> where Self:ToSegments {
   /// Filter characters in the specified range or type. Lets you filter by a set of character types (as an array)
   fn filter_by_types(&self, cts: &[CharType<'a>]) -> String;
 
+  /// Remove diacritics from Latin letters and expand common ligatures, e.g. "Crème brûlée" becomes "Creme brulee"
+  /// and "Straße" becomes "Strasse". Works with both precomposed and decomposed characters.
+  /// Letters of other scripts are unchanged
+  fn strip_diacritics(&self) -> String;
+
+  /// Build a canonical lookup key by lowercasing, stripping diacritics as with strip_diacritics()
+  /// and normalizing whitespace as with normalize_whitespace(), e.g. "  Café   Noir " and "cafe noir" both yield "cafe noir"
+  fn to_key(&self) -> String;
+
+  /// Remove emoji as matched by CharType::Emoji as well as the zero-width joiners, variation selectors and keycap marks
+  /// that combine them into sequences, e.g. "nice 👍🏽 job" becomes "nice  job". Surrounding whitespace is kept
+  fn strip_emoji(&self) -> String;
+
+  /// Trim the string and collapse all inner runs of whitespace, including newlines and tabs, to single spaces
+  fn normalize_whitespace(&self) -> String;
+
+  /// Remove simple markup tags from a "<" followed by a letter, "/", "!" or "?" to the next ">",
+  /// e.g. "<p>Hello <b>world</b></p>" becomes "Hello world".
+  /// This is a lightweight scanner rather than an HTML parser: it does not handle ">" within quoted attribute values,
+  /// script content or entities. An unterminated "<" is kept with the remaining text.
+  /// Chain with normalize_whitespace() to collapse the spaces and line breaks left between block elements
+  fn strip_html_tags(&self) -> String;
+
+  /// Unique characters in the order they first occur, e.g. ['b', 'a', 'n'] for "banana"
+  fn distinct_chars(&self) -> Vec<char>;
+
+  /// Unique characters sorted by their Unicode code points, e.g. ['a', 'b', 'n'] for "banana"
+  fn distinct_chars_sorted(&self) -> Vec<char> {
+    let mut chars = self.distinct_chars();
+    chars.sort_unstable();
+    chars
+  }
+
+  /// Remove all repeated characters keeping only the first occurrence of each, e.g. "mississippi" becomes "misp"
+  fn remove_duplicate_chars(&self) -> String {
+    self.distinct_chars().into_iter().collect()
+  }
+
+  /// Collapse runs of the same character to one, e.g. "loooong" becomes "long"
+  fn remove_consecutive_duplicates(&self) -> String;
+
+  /// Collapse runs of only the specified character to one, e.g. "a//b///c" becomes "a/b/c" with '/'
+  fn remove_consecutive_duplicates_of(&self, c: char) -> String;
+
   /// Extracts valid numeric string components from a longer string
   fn to_numeric_strings(&self) -> Vec<String> {
     self.to_numeric_strings_conditional(false)
@@ -93,6 +141,36 @@ pub trait StripCharacters<'a> where Self:ToSegments {
 
   fn to_numeric_strings_conditional(&self, enforce_comma_separator: bool) -> Vec<String>;
 
+  /// Extracts only numeric strings with a decimal separator, e.g. "3.50" from "2 apples cost 3.50"
+  fn to_float_strings(&self) -> Vec<String> {
+    self.to_numeric_strings().into_iter().filter(|num_str| num_str.contains('.')).collect()
+  }
+
+  /// Extracts only numeric strings without a decimal separator, e.g. "2" from "2 apples cost 3.50"
+  fn to_integer_strings(&self) -> Vec<String> {
+    self.to_numeric_strings().into_iter().filter(|num_str| !num_str.contains('.')).collect()
+  }
+
+  /// Extract numeric strings with the decimal and grouping separators of the specified number format
+  /// normalised with dots as decimal separators and without grouping separators.
+  /// A grouping separator is only valid between groups of exactly three digits after an initial group of one to three digits,
+  /// e.g. "1 234 567,89" with NumberFormat::new(',', Some(' ')) yields "1234567.89".
+  /// Otherwise the grouping separator is treated as a boundary between numbers
+  fn to_numeric_strings_with_format(&self, fmt: &NumberFormat) -> Vec<String>;
+
+  /// Extract numbers with the decimal and grouping separators of the specified number format
+  fn to_numbers_with_format<T: FromStr>(&self, fmt: &NumberFormat) -> Vec<T> {
+    self.to_numeric_strings_with_format(fmt).into_iter()
+      .filter_map(|s| s.parse::<T>().ok())
+      .collect()
+  }
+
+  /// Extract numbers using one number format inferred from all numeric substrings rather than deducing it per number,
+  /// e.g. "1.500 and 12,50" yields [1500.0, 12.5] as the decimal comma in 12,50 resolves the ambiguous 1.500.
+  /// A number with both separators decides the format, otherwise the format detected for most numbers wins.
+  /// If no format can be inferred or the evidence is tied, numbers are extracted as with to_numbers()
+  fn to_numbers_auto<T: FromStr>(&self) -> Vec<T>;
+
   /// Extract numeric strings and cast to numbers with conditional logic over commas and dots,
   /// The boolean flag enforces European logic where dots separate thousands and commas decimals
   /// Otherwise the correct format is deduced. Numeric strings are problematic when they only contain
@@ -100,27 +178,122 @@ pub trait StripCharacters<'a> where Self:ToSegments {
   /// it differs from the first separators.
   fn to_numbers_conditional<T: FromStr>(&self, enforce_comma_separator: bool) -> Vec<T>;
 
-  /// Extracts valid integers or floats from a longer string
+  /// Extracts valid integers or floats from a longer string.
+  /// A minus sign directly before the digits or before a single currency symbol ($, €, £, ¥, ₹, ₽, ₩, ₺, ₪ or ¢)
+  /// makes the number negative, e.g. "-$5.00 refund" yields -5.0
   fn to_numbers<T: FromStr>(&self) -> Vec<T> {
     self.to_numbers_conditional::<T>(false)
   }
 
+  /// Extracts valid integers or floats from a longer string, skipping the components of date-like patterns,
+  /// e.g. "Meeting on 12/05/2024 cost 300" yields only 300.
+  /// This is a heuristic: a date is a run of two or three groups of digits joined by the same / or - separator
+  /// with a day from 1 to 31 and month from 1 to 12 in either order, optionally followed by a 2- or 4-digit year,
+  /// or a 4-digit year followed by a month and optional day as in ISO 8601, e.g. 2024-05-12 or 2024-05.
+  /// A month and 4-digit year such as 05/2024 also counts as a date. Fractions or ranges with plausible values,
+  /// e.g. 3/4 or 10-12, are therefore also skipped
+  fn to_numbers_excluding_dates<T: FromStr>(&self) -> Vec<T>;
+
+  /// Extract numbers as with to_numbers() and group them into consecutive pairs, e.g. [(3, 4), (5, 6)] from "(3, 4) (5, 6)".
+  /// A trailing unpaired number is dropped
+  fn to_number_pairs<T: FromStr + Copy>(&self) -> Vec<(T, T)> {
+    self.to_numbers::<T>().chunks_exact(2).map(|pair| (pair[0], pair[1])).collect()
+  }
+
+  /// Extract numbers as with to_numbers() and group them into consecutive triples, e.g. [(1, 2, 3)] from "x1 y2 z3".
+  /// Trailing numbers that do not complete a triple are dropped
+  fn to_number_triples<T: FromStr + Copy>(&self) -> Vec<(T, T, T)> {
+    self.to_numbers::<T>().chunks_exact(3).map(|triple| (triple[0], triple[1], triple[2])).collect()
+  }
+
+  /// Extracts at most the specified number of integers or floats from the start of a longer string,
+  /// e.g. [12, 34] from "12/34/56 extra text" with a maximum of 2. Scanning stops as soon as enough numbers are found.
+  /// As with to_numbers(), a hyphen directly before digits is a minus sign, so "12-34" yields [12, -34].
+  /// Numeric strings that cannot be parsed as the number type, e.g. decimals as integers, are skipped and not counted
+  fn to_first_n_numbers<T: FromStr + Copy>(&self, n: usize) -> Vec<T>;
+
+  /// Extract integers written in the given base from whole alphanumeric tokens, e.g. "0x1F" or "ff9900" in "#ff9900" in base 16.
+  /// Tokens may start with a 0x, 0o or 0b prefix for bases 16, 8 and 2 and all remaining characters must be valid digits in the base.
+  /// In bases above 10, tokens without a prefix or a leading # must contain at least one decimal digit
+  /// so that words made only of the letters a-f, e.g. "bad", are not matched. Bare decimal numbers are read in the higher base,
+  /// so "0xFF and 10 items" yields [255, 16] in base 16. Returns an empty vector if the radix is not between 2 and 36
+  fn to_numbers_radix<T: FromStrRadix>(&self, radix: u32) -> Vec<T>;
+
+  /// Extract hexadecimal integers, e.g. [255, 31] from "0xFF and 0x1F"
+  fn to_hex_numbers<T: FromStrRadix>(&self) -> Vec<T> {
+    self.to_numbers_radix::<T>(16)
+  }
+
+  /// Extract binary integers, e.g. [10, 3] from "0b1010 and 0b11"
+  fn to_binary_numbers<T: FromStrRadix>(&self) -> Vec<T> {
+    self.to_numbers_radix::<T>(2)
+  }
+
   /// Extract numeric string using European-style decimal commas
   fn to_numbers_euro<T: FromStr>(&self) -> Vec<T> {
     self.to_numbers_conditional::<T>(true)
   }
+
+  /// Extract numbers paired with the numeric substrings they were parsed from before correction,
+  /// e.g. "1.234,5" yields (1234.5, "1.234,5"). The boolean flag enforces European logic as in to_numbers_conditional()
+  fn to_numbers_with_source_conditional<T: FromStr>(&self, enforce_comma_separator: bool) -> Vec<(T, String)>;
+
+  /// Extract numbers paired with their original numeric substrings for audit trails
+  fn to_numbers_with_source<T: FromStr>(&self) -> Vec<(T, String)> {
+    self.to_numbers_with_source_conditional::<T>(false)
+  }
+
+  /// Extracts valid integers or floats from a longer string, interpreting numbers tightly enclosed
+  /// in parentheses as negative as in financial statements, e.g. "(1,234.56)" yields -1234.56.
+  /// Parentheses containing other characters such as "(note 3)" are ignored and the number remains positive
+  fn to_numbers_accounting<T: FromStr>(&self) -> Vec<T>;
+
+  /// Extracts valid integers or floats from a longer string, interpreting a minus sign directly after the digits
+  /// as negation as in some exported ledgers, e.g. "balance 5.00-" yields -5.0.
+  /// This conflicts with ranges such as "5-10", so a trailing minus only counts if it is not followed by a letter or digit.
+  /// Otherwise the default scanner applies and "5-10" yields [5, -10] as with to_numbers()
+  fn to_numbers_trailing_sign<T: FromStr>(&self) -> Vec<T>;
   
+  /// Extract numbers with the alphabetic unit immediately following them or after a space,
+  /// e.g. "2.5kg of flour and 3 L of water" yields [(2.5, "kg"), (3.0, "L")].
+  /// Numbers must start a whitespace-separated word, optionally after symbols such as brackets or currency signs.
+  /// The unit is an empty string if the number is followed by punctuation or nothing, e.g. "72."
+  /// Any alphabetic word after a space is assumed to be a unit, so "2 apples" yields (2.0, "apples")
+  fn to_measurements(&self) -> Vec<(f64, String)>;
+
+  /// Extract numbers at the start of whitespace-separated words multiplying them by an attached scale suffix,
+  /// e.g. "1.5k views" yields 1500.0 and "2M users" 2000000.0. Supported suffixes are k or K for thousands,
+  /// M for millions, G or B for billions and T for trillions. M, G, B and T are case-sensitive, so "5m" (metres) is not scaled.
+  /// The suffix must be the whole alphabetic part after the number, so "5kg" is not scaled either
+  fn to_numbers_scaled(&self) -> Vec<f64>;
+
   /// Split a string on a separator and retunr a vector of all segments that may parsed as numbers
   /// This may fail with to_numbers() as the separator may be decimal or thousand separator
   fn split_to_numbers<T: FromStr + Copy>(&self, pattern: &str) -> Vec<T> {
     self.to_segments(pattern).into_iter().filter_map(|part| part.to_first_number::<T>()).collect::<Vec<T>>()
   }
 
+  /// Split a string on a separator, trim whitespace from each segment and parse whole segments as numbers,
+  /// e.g. [1, 2, 3] from "  1 , 2 ,3 " with ",". Unlike split_to_numbers(), segments that do not parse as a whole, such as "2 kg", are skipped
+  fn split_trim_numbers<T: FromStr + Copy>(&self, separator: &str) -> Vec<T> {
+    self.to_segments(separator).into_iter().filter_map(|part| part.trim().parse::<T>().ok()).collect::<Vec<T>>()
+  }
+
   /// Correct numbers to conform to use dots (periods, full-stops) only as decimal separators
   /// Works only on the first number encountered and used with to_numeric_strings or to_numeric_strings_euro
   /// to correct multiple numbers in a longer string
   fn correct_numeric_string(&self, enforce_comma_separator: bool) -> String;
 
+  /// Variant of correct_numeric_string() that borrows the original string if no correction is needed,
+  /// e.g. "15.00", avoiding allocation in tight loops
+  fn correct_numeric_string_cow(&self, enforce_comma_separator: bool) -> Cow<'_, str>;
+
+  /// Deduce the decimal and grouping separators of the first numeric sequence in the string
+  /// following the same logic as correct_numeric_string(). If both commas and dots are present
+  /// the last one is the decimal separator. A single separator followed by exactly three digits,
+  /// e.g. 1.500, is ambiguous and yields None as do numbers without separators
+  fn detect_number_format(&self) -> Option<NumberFormat>;
+
   /// Extracts the first valid integer or float from a longer string if present
   fn to_first_number<T: FromStr + Copy>(&self) -> Option<T> {
     if let Some(number) = self.to_numbers::<T>().first() {
@@ -140,6 +313,12 @@ pub trait StripCharacters<'a> where Self:ToSegments {
     }
   }
 
+  /// Extracts the first valid integer or float from a longer string using the decimal and grouping separators
+  /// of the specified number format, e.g. 1234.5 from "CHF 1'234.50" with NumberFormat::new('.', Some('\''))
+  fn to_first_number_with_format<T: FromStr + Copy>(&self, fmt: &NumberFormat) -> Option<T> {
+    self.to_numeric_strings_with_format(fmt).into_iter().find_map(|s| s.parse::<T>().ok())
+  }
+
   /// Removes all characters not used in valid numeric sequences
   /// with single spaces between numbers
   fn strip_non_numeric(&self) -> String {
@@ -169,6 +348,16 @@ impl<'a> StripCharacters<'a> for str {
     self.chars().into_iter().filter(|c| ct.is_in_range(c) == false).collect::<String>()
   }
 
+  fn strip_by_type_counted(&self, ct: CharType<'a>) -> (String, usize) {
+    let mut num_removed = 0;
+    let stripped = self.chars().filter(|c| {
+      let is_removed = ct.is_in_range(c);
+      num_removed += is_removed as usize;
+      !is_removed
+    }).collect::<String>();
+    (stripped, num_removed)
+  }
+
   /// remove all characters in the specified set of categories or ranges
   fn strip_by_types(&self, cts: &[CharType<'a>]) -> String {
     self.chars().into_iter().filter(|c| cts.iter().any(|ct| ct.is_in_range(c)) == false).collect::<String>()
@@ -184,6 +373,64 @@ impl<'a> StripCharacters<'a> for str {
     self.chars().into_iter().filter(|c| cts.iter().any(|ct| ct.is_in_range(c))).collect::<String>()
   }
 
+  fn strip_diacritics(&self) -> String {
+    strip_diacritics(self)
+  }
+
+  fn to_key(&self) -> String {
+    fold_for_match(self).normalize_whitespace()
+  }
+
+  fn strip_emoji(&self) -> String {
+    self.chars().filter(|&c| !is_emoji(c) && !is_emoji_component(c)).collect()
+  }
+
+  fn normalize_whitespace(&self) -> String {
+    self.split_whitespace().collect::<Vec<&str>>().join(" ")
+  }
+
+  fn strip_html_tags(&self) -> String {
+    let mut output = String::with_capacity(self.len());
+    let mut rest = self;
+    while let Some(start) = rest.find('<') {
+      output.push_str(&rest[..start]);
+      let tag = &rest[start..];
+      let is_tag_start = tag[1..].starts_with(|c: char| c.is_alphabetic() || matches!(c, '/' | '!' | '?'));
+      match tag.find('>') {
+        Some(end) if is_tag_start => {
+          rest = &tag[end + 1..];
+        },
+        _ => {
+          // keep a lone or unterminated angle bracket as text
+          output.push('<');
+          rest = &tag[1..];
+        }
+      }
+    }
+    output.push_str(rest);
+    output
+  }
+
+  fn distinct_chars(&self) -> Vec<char> {
+    let mut chars: Vec<char> = Vec::new();
+    for c in self.chars() {
+      if !chars.contains(&c) {
+        chars.push(c);
+      }
+    }
+    chars
+  }
+
+  fn remove_consecutive_duplicates(&self) -> String {
+    let mut prev_char: Option<char> = None;
+    self.chars().filter(|&c| prev_char.replace(c) != Some(c)).collect::<String>()
+  }
+
+  fn remove_consecutive_duplicates_of(&self, c: char) -> String {
+    let mut prev_char: Option<char> = None;
+    self.chars().filter(|&current| prev_char.replace(current) != Some(c) || current != c).collect::<String>()
+  }
+
   /// Correct numeric strings with commas as thousand separators or as decimal separators
   /// to a regular format with punctuation only for decimal points before being parsed to an integer or float
   /// This is best used only with numeric strings as it will strip commas and dots not used as decimal separators
@@ -205,76 +452,202 @@ impl<'a> StripCharacters<'a> for str {
       }
   }
 
+  /// Only strings without commas and at most one dot, which is not a thousand separator in European mode, are unchanged
+  fn correct_numeric_string_cow(&self, enforce_comma_separator: bool) -> Cow<'_, str> {
+    let num_points = self.matches('.').count();
+    if !self.contains(',') && (num_points < 1 || (num_points == 1 && !enforce_comma_separator)) {
+      Cow::Borrowed(self)
+    } else {
+      Cow::Owned(self.correct_numeric_string(enforce_comma_separator))
+    }
+  }
+
+  /// Inspect the first sequence of digits, commas and dots
+  fn detect_number_format(&self) -> Option<NumberFormat> {
+    let start = self.find(|c: char| c.is_ascii_digit())?;
+    let num_len = self[start..].find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).unwrap_or(self.len() - start);
+    let num_str = self[start..start + num_len].trim_end_matches(['.', ',']);
+    let commas = num_str.find_matched_indices(",");
+    let points = num_str.find_matched_indices(".");
+    match (commas.last(), points.last()) {
+      // the last separator is the decimal separator and may only occur once
+      (Some(&comma_index), Some(&point_index)) => {
+        if comma_index > point_index && commas.len() == 1 {
+          Some(NumberFormat::euro())
+        } else if point_index > comma_index && points.len() == 1 {
+          Some(NumberFormat::standard())
+        } else {
+          None
+        }
+      },
+      (Some(&comma_index), None) => detect_format_from_separator(num_str, ',', comma_index, commas.len()),
+      (None, Some(&point_index)) => detect_format_from_separator(num_str, '.', point_index, points.len()),
+      _ => None
+    }
+  }
+
   /// conditionally extract numeric strings from a longer string
   fn to_numeric_strings_conditional(&self, enforce_comma_separator: bool) -> Vec<String> {
-    let mut prev_char = ' ';
-    let mut seq_num = 0;
-    let mut num_string = String::new();
     let mut output: Vec<String> = Vec::new();
-    let last_index = self.chars().count().checked_sub(1).unwrap_or(0);
-    let mut index: usize = 0;
-    let mut prev_is_separator = false;
-    for component in self.chars() {
-      let mut is_end = index == last_index;
-      let is_digit = component.is_digit(10);
-      // if the previous char is a separator and the current is not digit
-      // check if there is a valid temporary numeric string to be added below
-      if prev_is_separator && !is_digit {
-        let num_str_len = num_string.len();
-        if num_str_len > 1 {
-          // strip the final separator-like character
-          num_string = (&num_string[0..num_str_len - 1]).to_string();
-          is_end = true;
-          seq_num  = num_string.len(); 
+    for num_string in extract_raw_numeric_strings(self) {
+      add_sanitized_numeric_string(&mut output, &num_string.correct_numeric_string(enforce_comma_separator));
+    }
+    output
+  }
+
+  fn to_numbers_radix<T: FromStrRadix>(&self, radix: u32) -> Vec<T> {
+    // char::is_digit() panics for a radix above 36 and from_str_radix() for any below 2
+    if !(2..=36).contains(&radix) {
+      return vec![];
+    }
+    let prefix = match radix {
+      16 => Some("0x"),
+      8 => Some("0o"),
+      2 => Some("0b"),
+      _ => None
+    };
+    let mut numbers: Vec<T> = Vec::new();
+    for (prev_char, token) in to_alphanumeric_tokens(self) {
+      let (digits, has_prefix) = match prefix {
+        Some(pre) if token.len() > pre.len() && token.get(..pre.len()).is_some_and(|start| start.eq_ignore_ascii_case(pre)) => (&token[pre.len()..], true),
+        _ => (token, radix == 16 && prev_char == Some('#')),
+      };
+      let is_valid = digits.chars().all(|c| c.is_digit(radix))
+        && (has_prefix || radix <= 10 || digits.chars().any(|c| c.is_ascii_digit()));
+      if is_valid {
+        if let Ok(number) = T::from_str_radix(digits, radix) {
+          numbers.push(number);
         }
       }
-      if is_digit {
-        if prev_char == '-' {
-          num_string.push(prev_char);  
+    }
+    numbers
+  }
+
+  fn to_first_n_numbers<T: FromStr + Copy>(&self, n: usize) -> Vec<T> {
+    let mut numbers: Vec<T> = Vec::new();
+    if n > 0 {
+      scan_raw_numeric_strings(self, |raw| {
+        let num_string = raw.correct_numeric_string(false);
+        if let Ok(number) = num_string.trim_end_matches(['.', ',']).parse::<T>() {
+          numbers.push(number);
         }
-        num_string.push(component);
-        seq_num += 1;
-        prev_is_separator = false;
-      } else if prev_char.is_digit(10) {
-        match component {
-          '.' | '․' | ',' => {
-            // ignore final decimal or thousand separator if this is last character
-            if index == last_index {
-              is_end = true;
-            } else {
-              if component == ',' {
-                num_string.push(',');
-              } else {
-                num_string.push('.');
-              }
-              // reset the sequence number at the end of a digit sequence
-              seq_num = 0;
-            }
-            prev_is_separator = true;
-          },
-          _ => {
-            is_end = true;
-          }
+        numbers.len() < n
+      });
+    }
+    numbers
+  }
+
+  /// Correct and parse each raw numeric string while keeping the raw string alongside
+  fn to_numbers_with_source_conditional<T: FromStr>(&self, enforce_comma_separator: bool) -> Vec<(T, String)> {
+    extract_raw_numeric_strings(self).into_iter()
+      .filter_map(|raw| {
+        let num_string = raw.correct_numeric_string(enforce_comma_separator);
+        num_string.trim_end_matches(['.', ',']).parse::<T>().ok().map(|value| (value, raw))
+      })
+      .collect()
+  }
+
+  /// Rewrite parenthesized numbers with a leading minus before extracting numbers
+  fn to_numbers_accounting<T: FromStr>(&self) -> Vec<T> {
+    accounting_negatives_to_signed(self).to_numbers_conditional::<T>(false)
+  }
+
+  fn to_numbers_trailing_sign<T: FromStr>(&self) -> Vec<T> {
+    trailing_minus_to_signed(self).to_numbers_conditional::<T>(false)
+  }
+
+  /// Scan characters for digit sequences with valid grouping and decimal separators
+  fn to_numeric_strings_with_format(&self, fmt: &NumberFormat) -> Vec<String> {
+    let chars: Vec<char> = self.chars().collect();
+    let num_chars = chars.len();
+    let is_digit_at = |index: usize| index < num_chars && chars[index].is_ascii_digit();
+    let mut output: Vec<String> = Vec::new();
+    let mut num_string = String::new();
+    let mut group_len = 0;
+    let mut num_groups = 0;
+    let mut has_decimal = false;
+    for (index, &c) in chars.iter().enumerate() {
+      if c.is_ascii_digit() {
+        if num_string.is_empty() && index > 0 && has_minus_prefix(chars[index - 1], index.checked_sub(2).map(|i| chars[i])) {
+          num_string.push('-');
         }
-      } else {
-        is_end = true;
-        prev_is_separator = false;
+        num_string.push(c);
+        group_len += 1;
+        continue;
       }
-      if is_end {
-        if seq_num > 0 {
-          add_sanitized_numeric_string(&mut output, &num_string.correct_numeric_string(enforce_comma_separator));
-          // reset the mutable string to start the next nunber afresh
-          num_string = String::new();
-          // reset the sequence number at the end of a captured number string
-          seq_num = 0;
+      if group_len > 0 {
+        let is_valid_group = if num_groups < 1 { group_len <= 3 } else { group_len == 3 };
+        if !has_decimal && fmt.is_grouping_char(c) && is_valid_group
+          && (1..=3).all(|offset| is_digit_at(index + offset)) && !is_digit_at(index + 4) {
+          num_groups += 1;
+          group_len = 0;
+          continue;
+        }
+        if !has_decimal && c == fmt.decimal_separator && is_digit_at(index + 1) {
+          num_string.push('.');
+          has_decimal = true;
+          group_len = 0;
+          continue;
         }
       }
-      prev_char = component;
-      index += 1;
+      if !num_string.is_empty() {
+        output.push(std::mem::take(&mut num_string));
+      }
+      group_len = 0;
+      num_groups = 0;
+      has_decimal = false;
+    }
+    if !num_string.is_empty() {
+      output.push(num_string);
     }
     output
   }
 
+  /// Scan whitespace-separated words for leading numbers and adjacent units
+  fn to_measurements(&self) -> Vec<(f64, String)> {
+    let mut measurements: Vec<(f64, String)> = Vec::new();
+    let mut words = self.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+      if let Some((value, rest)) = split_leading_number(word) {
+        let unit = if rest.is_empty() && !word.ends_with([',', '.']) {
+          // check if the next word is a unit on its own, unless the number ends a clause, e.g. "72," or "72."
+          words.next_if(|next_word| trim_unit_word(next_word).chars().all(char::is_alphabetic))
+            .map_or("", trim_unit_word)
+        } else {
+          let unit_len = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+          &rest[..unit_len]
+        };
+        measurements.push((value, unit.to_string()));
+      }
+    }
+    measurements
+  }
+
+  fn to_numbers_scaled(&self) -> Vec<f64> {
+    self.split_whitespace().filter_map(split_leading_number).map(|(value, rest)| {
+      let suffix_len = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+      let factor = match &rest[..suffix_len] {
+        "k" | "K" => 1_000.0,
+        "M" => 1_000_000.0,
+        "G" | "B" => 1_000_000_000.0,
+        "T" => 1_000_000_000_000.0,
+        _ => 1.0
+      };
+      value * factor
+    }).collect()
+  }
+
+  fn to_numbers_excluding_dates<T: FromStr>(&self) -> Vec<T> {
+    mask_dates(self).to_numbers::<T>()
+  }
+
+  fn to_numbers_auto<T: FromStr>(&self) -> Vec<T> {
+    match detect_dominant_number_format(self) {
+      Some(fmt) => self.to_numbers_with_format::<T>(&fmt),
+      None => self.to_numbers::<T>()
+    }
+  }
+
   /// Scan the sample string for numeric strings and parse them as the specified number type
   fn to_numbers_conditional<T: FromStr>(&self, enforce_comma_separator: bool) -> Vec<T> {
     self.to_numeric_strings_conditional(enforce_comma_separator).into_iter()
@@ -286,6 +659,245 @@ impl<'a> StripCharacters<'a> for str {
 }
 
 
+/// A minus sign directly before a number or separated from it only by one of these currency symbols,
+/// e.g. -$5.00 or -€20, makes the number negative
+const CURRENCY_SYMBOLS: [char; 10] = ['$', '€', '£', '¥', '₹', '₽', '₩', '₺', '₪', '¢'];
+
+/// Check if the characters before the first digit of a number mark it as negative
+fn has_minus_prefix(prev_char: char, before_prev_char: Option<char>) -> bool {
+  prev_char == '-' || (CURRENCY_SYMBOLS.contains(&prev_char) && before_prev_char == Some('-'))
+}
+
+/// Extract numeric strings as they appear in the source string, with commas and dots as separators
+/// but before thousand separators are removed and decimal commas corrected.
+/// A leading minus sign is kept and a final separator stripped
+fn extract_raw_numeric_strings(txt: &str) -> Vec<String> {
+  let mut output: Vec<String> = Vec::new();
+  scan_raw_numeric_strings(txt, |num_string| {
+    output.push(num_string);
+    true
+  });
+  output
+}
+
+/// Pass each raw numeric string to the callback as soon as it is complete.
+/// Scanning stops early if the callback returns false
+fn scan_raw_numeric_strings(txt: &str, mut on_number: impl FnMut(String) -> bool) {
+  let mut prev_char = ' ';
+  let mut before_prev_char: Option<char> = None;
+  let mut seq_num = 0;
+  let mut num_string = String::new();
+  let mut prev_is_separator = false;
+  // peek ahead to detect the last character without counting all characters up front
+  let mut chars = txt.chars().peekable();
+  while let Some(component) = chars.next() {
+    let is_last = chars.peek().is_none();
+    let mut is_end = is_last;
+    let is_digit = component.is_digit(10);
+    // if the previous char is a separator and the current is not digit
+    // check if there is a valid temporary numeric string to be added below
+    if prev_is_separator && !is_digit {
+      let num_str_len = num_string.len();
+      if num_str_len > 1 {
+        // strip the final separator-like character
+        num_string = (&num_string[0..num_str_len - 1]).to_string();
+        is_end = true;
+        seq_num  = num_string.len(); 
+      }
+    }
+    if is_digit {
+      if has_minus_prefix(prev_char, before_prev_char) {
+        num_string.push('-');
+      }
+      num_string.push(component);
+      seq_num += 1;
+      prev_is_separator = false;
+    } else if prev_char.is_digit(10) {
+      match component {
+        '.' | '․' | ',' => {
+          // ignore final decimal or thousand separator if this is last character
+          if is_last {
+            is_end = true;
+          } else {
+            if component == ',' {
+              num_string.push(',');
+            } else {
+              num_string.push('.');
+            }
+            // reset the sequence number at the end of a digit sequence
+            seq_num = 0;
+          }
+          prev_is_separator = true;
+        },
+        _ => {
+          is_end = true;
+        }
+      }
+    } else {
+      is_end = true;
+      prev_is_separator = false;
+    }
+    if is_end {
+      if seq_num > 0 {
+        // reset the mutable string to start the next nunber afresh
+        if !on_number(std::mem::take(&mut num_string)) {
+          return;
+        }
+        // reset the sequence number at the end of a captured number string
+        seq_num = 0;
+      }
+    }
+    before_prev_char = Some(prev_char);
+    prev_char = component;
+  }
+}
+
+/// Check if a character may be part of a date-like run of digits and separators
+fn is_date_run_char(c: char) -> bool {
+  c.is_ascii_digit() || c == '/' || c == '-'
+}
+
+/// Replace the digits and inner separators of date-like runs with spaces, leaving all other characters intact
+fn mask_dates(txt: &str) -> String {
+  let mut output = String::with_capacity(txt.len());
+  let mut run_start: Option<usize> = None;
+  for (index, c) in txt.char_indices().chain([(txt.len(), ' ')]) {
+    if is_date_run_char(c) {
+      run_start.get_or_insert(index);
+      continue;
+    }
+    if let Some(start) = run_start.take() {
+      let run = &txt[start..index];
+      let core = run.trim_matches(['/', '-']);
+      if is_date_like(core) {
+        let lead_len = run.len() - run.trim_start_matches(['/', '-']).len();
+        output.push_str(&run[..lead_len]);
+        output.push_str(&" ".repeat(core.len()));
+        output.push_str(&run[lead_len + core.len()..]);
+      } else {
+        output.push_str(run);
+      }
+    }
+    if index < txt.len() {
+      output.push(c);
+    }
+  }
+  output
+}
+
+/// Check if digit groups joined by a single type of separator form a plausible date as described for to_numbers_excluding_dates()
+fn is_date_like(core: &str) -> bool {
+  let Some(separator) = core.chars().find(|c| !c.is_ascii_digit()) else {
+    return false;
+  };
+  let parts: Vec<&str> = core.split(separator).collect();
+  if parts.iter().any(|part| part.is_empty() || part.len() > 4 || !part.chars().all(|c| c.is_ascii_digit())) {
+    return false;
+  }
+  let values: Vec<u32> = parts.iter().filter_map(|part| part.parse::<u32>().ok()).collect();
+  let is_short = |index: usize| parts[index].len() <= 2;
+  let is_month = |index: usize| is_short(index) && (1..=12).contains(&values[index]);
+  let is_day = |index: usize| is_short(index) && (1..=31).contains(&values[index]);
+  let is_day_month = |first: usize, second: usize| is_day(first) && is_day(second) && (is_month(first) || is_month(second));
+  let is_year = |index: usize| parts[index].len() == 4;
+  match parts.len() {
+    2 => is_day_month(0, 1) || (is_month(0) && is_year(1)) || (is_year(0) && is_month(1)),
+    3 => (is_day_month(0, 1) && (parts[2].len() == 2 || is_year(2))) || (is_year(0) && is_month(1) && is_day(2)),
+    _ => false
+  }
+}
+
+/// Split a string into runs of alphanumeric characters, each paired with the character immediately before it if any
+fn to_alphanumeric_tokens(txt: &str) -> Vec<(Option<char>, &str)> {
+  let mut tokens: Vec<(Option<char>, &str)> = Vec::new();
+  let mut token_start: Option<(usize, Option<char>)> = None;
+  let mut prev_char: Option<char> = None;
+  for (index, c) in txt.char_indices() {
+    if c.is_alphanumeric() {
+      if token_start.is_none() {
+        token_start = Some((index, prev_char));
+      }
+    } else if let Some((start, before)) = token_start.take() {
+      tokens.push((before, &txt[start..index]));
+    }
+    prev_char = Some(c);
+  }
+  if let Some((start, before)) = token_start {
+    tokens.push((before, &txt[start..]));
+  }
+  tokens
+}
+
+/// Parse the number at the start of a whitespace-separated word, optionally after symbols such as brackets or currency signs,
+/// and return it with the remainder of the word, e.g. (2.5, "kg") for "2.5kg"
+fn split_leading_number(word: &str) -> Option<(f64, &str)> {
+  let word = word.trim_start_matches(|c: char| !c.is_alphanumeric() && c != '-');
+  if !word.trim_start_matches('-').starts_with(|c: char| c.is_ascii_digit()) {
+    return None;
+  }
+  let num_len = word[1..].find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).map_or(word.len(), |index| index + 1);
+  word[..num_len].to_first_number::<f64>().map(|value| (value, &word[num_len..]))
+}
+
+/// Remove trailing punctuation from a word that may be a unit of measurement, e.g. "L," becomes "L"
+fn trim_unit_word(word: &str) -> &str {
+  word.trim_end_matches(|c: char| c.is_ascii_punctuation())
+}
+
+/// Infer the number format of a whole string from all its numeric substrings.
+/// A number with both commas and dots is decisive, otherwise the format detected for most numbers wins
+fn detect_dominant_number_format(txt: &str) -> Option<NumberFormat> {
+  let mut num_euro = 0;
+  let mut num_standard = 0;
+  for num_str in extract_raw_numeric_strings(txt) {
+    if let Some(fmt) = num_str.detect_number_format() {
+      if num_str.contains(',') && num_str.contains('.') {
+        return Some(fmt);
+      }
+      if fmt.has_decimal_comma() {
+        num_euro += 1;
+      } else {
+        num_standard += 1;
+      }
+    }
+  }
+  match num_euro.cmp(&num_standard) {
+    std::cmp::Ordering::Greater => Some(NumberFormat::euro()),
+    std::cmp::Ordering::Less => Some(NumberFormat::standard()),
+    std::cmp::Ordering::Equal => None
+  }
+}
+
+/// Deduce the number format of a numeric string with only one type of separator.
+/// Repeated separators must be grouping separators, while a single separator is a decimal separator
+/// unless followed by exactly three digits, which is ambiguous
+fn detect_format_from_separator(num_str: &str, separator: char, last_index: usize, num_separators: usize) -> Option<NumberFormat> {
+  let is_decimal = num_separators == 1;
+  let num_following_digits = num_str.len() - last_index - 1;
+  if is_decimal && num_following_digits == 3 {
+    None
+  } else if is_decimal == (separator == ',') {
+    Some(NumberFormat::euro())
+  } else {
+    Some(NumberFormat::standard())
+  }
+}
+
+/// Mutating variants of StripCharacters methods for owned strings that reuse the existing buffer
+pub trait StripCharactersMut<'a> {
+  /// Remove characters in the specified character category/range in place without reallocating
+  fn strip_by_type_in_place(&mut self, ct: CharType<'a>);
+}
+
+impl<'a> StripCharactersMut<'a> for String {
+
+  /// remove all characters in the specified category or range via String::retain
+  fn strip_by_type_in_place(&mut self, ct: CharType<'a>) {
+    self.retain(|c| !ct.is_in_range(&c));
+  }
+
+}
+
 /// Methods to validate strings with character classes
 pub trait CharGroupMatch {
   /// Does the string contain any decimal digits
@@ -300,11 +912,39 @@ pub trait CharGroupMatch {
   /// Does the string contain any letters including those from non-Latin alphabets, but excluding digits
   fn has_alphabetic(&self) -> bool;
 
+  /// Does the string contain only decimal digits. Empty strings are not valid digit strings
   fn is_digits_only(&self) -> bool;
 
-  /// Does the string contain any digits any supported radix
+  /// Does the string contain only digits in the specified radix. Empty strings are not valid digit strings
   fn is_digits_only_radix(&self, radix: u8) -> bool;
 
+  /// Is the string empty or does it contain only whitespace
+  fn is_blank(&self) -> bool;
+
+  /// Does the string contain only whitespace. Unlike is_blank() empty strings yield false
+  fn is_whitespace_only(&self) -> bool;
+
+  /// Return the first character that is not whitespace if any
+  fn first_non_whitespace(&self) -> Option<char>;
+
+  /// Check if words are separated only by single spaces without leading or trailing whitespace,
+  /// i.e. the string is unchanged by normalize_whitespace(). Tabs and line breaks are not allowed
+  fn has_normalized_whitespace(&self) -> bool;
+
+  /// Does the string contain any uppercase letters including those from non-Latin alphabets
+  fn has_upper(&self) -> bool;
+
+  /// Does the string contain any lowercase letters including those from non-Latin alphabets
+  fn has_lower(&self) -> bool;
+
+  /// Does the string contain any emoji as matched by CharType::Emoji
+  fn has_emoji(&self) -> bool;
+
+  /// Does the string contain both uppercase and lowercase letters, e.g. "Abc" but not "abc" or "123"
+  fn has_mixed_case(&self) -> bool {
+    self.has_upper() && self.has_lower()
+  }
+
 }
 
 impl CharGroupMatch for str {
@@ -326,12 +966,52 @@ impl CharGroupMatch for str {
   }
 
   fn is_digits_only(&self) -> bool {
-    self.chars().all(|c| c.is_ascii_digit())
+    !self.is_empty() && self.chars().all(|c| c.is_ascii_digit())
   }
 
-  /// Does the string contain any digits any supported radix
+  /// Does the string contain only digits in the specified radix
   fn is_digits_only_radix(&self, radix: u8) -> bool {
-    self.chars().all(|c| c.is_digit(radix as u32))
+    !self.is_empty() && self.chars().all(|c| c.is_digit(radix as u32))
+  }
+
+  fn is_blank(&self) -> bool {
+    self.chars().all(char::is_whitespace)
+  }
+
+  fn is_whitespace_only(&self) -> bool {
+    !self.is_empty() && self.is_blank()
+  }
+
+  fn first_non_whitespace(&self) -> Option<char> {
+    self.chars().find(|c| !c.is_whitespace())
+  }
+
+  fn has_normalized_whitespace(&self) -> bool {
+    // start as if preceded by a space to reject leading whitespace
+    let mut prev_is_space = true;
+    for c in self.chars() {
+      if c.is_whitespace() {
+        if c != ' ' || prev_is_space {
+          return false;
+        }
+        prev_is_space = true;
+      } else {
+        prev_is_space = false;
+      }
+    }
+    !self.ends_with(' ')
+  }
+
+  fn has_upper(&self) -> bool {
+    self.chars().any(|c| CharType::Upper.is_in_range(&c))
+  }
+
+  fn has_lower(&self) -> bool {
+    self.chars().any(|c| CharType::Lower.is_in_range(&c))
+  }
+
+  fn has_emoji(&self) -> bool {
+    self.chars().any(is_emoji)
   }
 
 }
@@ -0,0 +1,70 @@
+use crate::{utils::match_ci_by_position, BoundsPosition};
+
+/// Default words interpreted as true
+pub const TRUTHY_STRS: [&str; 6] = ["yes", "y", "true", "ok", "1", "on"];
+
+/// Default words interpreted as false
+pub const FALSY_STRS: [&str; 5] = ["no", "n", "false", "0", "off"];
+
+/// Sets of words recognised as true or false by to_bool_with(),
+/// e.g. BoolStrings::new(&["ja", "oui"], &["nein", "non"]) for German and French answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoolStrings<'a> {
+  pub truthy: &'a [&'a str],
+  pub falsy: &'a [&'a str],
+}
+
+impl<'a> BoolStrings<'a> {
+  pub fn new(truthy: &'a [&'a str], falsy: &'a [&'a str]) -> Self {
+    BoolStrings {
+      truthy,
+      falsy
+    }
+  }
+}
+
+impl Default for BoolStrings<'static> {
+  fn default() -> Self {
+    BoolStrings::new(&TRUTHY_STRS, &FALSY_STRS)
+  }
+}
+
+/// Interpret boolean-like strings such as "Yes", "off" or "1"
+/// Surrounding whitespace is ignored and words are matched case-insensitively
+pub trait ToBool {
+
+  /// Interpret the string as true or false with custom sets of words or None if it matches neither
+  fn to_bool_with(&self, words: &BoolStrings) -> Option<bool>;
+
+  /// Interpret the string as true or false or None if it is not recognised
+  /// True: yes, y, true, ok, 1, on. False: no, n, false, 0, off
+  fn to_bool(&self) -> Option<bool> {
+    self.to_bool_with(&BoolStrings::default())
+  }
+
+  /// Check if the string is recognised as true, e.g. "Yes" or "ON"
+  fn is_truthy(&self) -> bool {
+    self.to_bool() == Some(true)
+  }
+
+  /// Check if the string is recognised as false, e.g. "No" or "off"
+  fn is_falsy(&self) -> bool {
+    self.to_bool() == Some(false)
+  }
+
+}
+
+impl ToBool for str {
+
+  fn to_bool_with(&self, words: &BoolStrings) -> Option<bool> {
+    let txt = self.trim();
+    if words.truthy.iter().any(|word| match_ci_by_position(txt, word, BoundsPosition::Whole)) {
+      Some(true)
+    } else if words.falsy.iter().any(|word| match_ci_by_position(txt, word, BoundsPosition::Whole)) {
+      Some(false)
+    } else {
+      None
+    }
+  }
+
+}
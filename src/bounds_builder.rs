@@ -1,4 +1,4 @@
-use crate::{enums::StringBounds, utils::{strs_to_negative_string_bounds, strs_to_string_bounds}, BoundsPosition, CaseMatchMode};
+use crate::{enums::StringBounds, utils::{strs_to_negative_string_bounds, strs_to_string_bounds}, BoundsPosition, CaseMatchMode, CharType};
 
 /// Build a set of string matching rules
 #[derive(Debug, Clone)]
@@ -147,6 +147,32 @@ impl<'a> BoundsBuilder<'a> {
     self.not_containing(pattern, false)
   }
 
+  /// Add a "contains word" rule matching the pattern as a whole word with a positive and case-insensitive flags
+  pub fn contains_word(&mut self, pattern: &'a str, is_positive: bool, case_insensitive: bool) -> Self {
+    self.string_bounds.push(StringBounds::ContainsWord(pattern, is_positive, CaseMatchMode::insensitive(case_insensitive)));
+    self.to_owned()
+  }
+
+  /// Add a positive "contains word" rule in case-insensitive mode, e.g. "cat" matches "The Cat sat", but not "category"
+  pub fn containing_word_ci(&mut self, pattern: &'a str) -> Self {
+    self.contains_word(pattern, true, true)
+  }
+
+  /// Add a positive "contains word" rule in case-sensitive mode
+  pub fn containing_word_cs(&mut self, pattern: &'a str) -> Self {
+    self.contains_word(pattern, true, false)
+  }
+
+  /// Add a negative "contains word" rule in case-insensitive mode
+  pub fn not_containing_word_ci(&mut self, pattern: &'a str) -> Self {
+    self.contains_word(pattern, false, true)
+  }
+
+  /// Add a negative "contains word" rule in case-sensitive mode
+  pub fn not_containing_word_cs(&mut self, pattern: &'a str) -> Self {
+    self.contains_word(pattern, false, false)
+  }
+
   /// Add an "ends_with" rule with a positive and case-insensitive flags
   fn ends_with(&mut self, pattern: &'a str, is_positive: bool, case_insensitive: bool) -> Self {
     let cm = if case_insensitive {
@@ -255,6 +281,65 @@ impl<'a> BoundsBuilder<'a> {
     self.matches_whole(pattern, false, false)
   }
 
+  /// Add a "starts_with" rule with a positive flag folding only ASCII letters for fast comparison without allocation
+  pub fn starts_with_ascii_ci(&mut self, pattern: &'a str, is_positive: bool) -> Self {
+    self.string_bounds.push(StringBounds::StartsWith(pattern, is_positive, CaseMatchMode::AsciiInsensitive));
+    self.to_owned()
+  }
+
+  /// Add a positive "starts_with" rule folding only ASCII letters
+  pub fn starting_with_ascii_ci(&mut self, pattern: &'a str) -> Self {
+    self.starts_with_ascii_ci(pattern, true)
+  }
+
+  /// Add an "ends_with" rule with a positive flag folding only ASCII letters
+  pub fn ends_with_ascii_ci(&mut self, pattern: &'a str, is_positive: bool) -> Self {
+    self.string_bounds.push(StringBounds::EndsWith(pattern, is_positive, CaseMatchMode::AsciiInsensitive));
+    self.to_owned()
+  }
+
+  /// Add a positive "ends_with" rule folding only ASCII letters
+  pub fn ending_with_ascii_ci(&mut self, pattern: &'a str) -> Self {
+    self.ends_with_ascii_ci(pattern, true)
+  }
+
+  /// Add a "contains" rule with a positive flag folding only ASCII letters
+  pub fn contains_ascii_ci(&mut self, pattern: &'a str, is_positive: bool) -> Self {
+    self.string_bounds.push(StringBounds::Contains(pattern, is_positive, CaseMatchMode::AsciiInsensitive));
+    self.to_owned()
+  }
+
+  /// Add a positive "contains" rule folding only ASCII letters
+  pub fn containing_ascii_ci(&mut self, pattern: &'a str) -> Self {
+    self.contains_ascii_ci(pattern, true)
+  }
+
+  /// Add a positive "whole_match" rule folding only ASCII letters
+  pub fn is_ascii_ci(&mut self, pattern: &'a str) -> Self {
+    self.string_bounds.push(StringBounds::Whole(pattern, true, CaseMatchMode::AsciiInsensitive));
+    self.to_owned()
+  }
+
+  /// Add a rule requiring at least one character of the type, e.g. a digit with CharType::DecDigit
+  pub fn containing_type(&mut self, char_type: CharType<'a>) -> Self {
+    self.string_bounds.push(StringBounds::HasType(char_type, true));
+    self.to_owned()
+  }
+
+  /// Add a rule rejecting strings with any character of the type
+  pub fn not_containing_type(&mut self, char_type: CharType<'a>) -> Self {
+    self.string_bounds.push(StringBounds::HasType(char_type, false));
+    self.to_owned()
+  }
+
+  /// Add a rule requiring a match of the regular expression or rejecting matches if not positive.
+  /// An invalid pattern never matches, but is reported by try_match_all_conditional()
+  #[cfg(feature = "regex")]
+  pub fn matching_regex(&mut self, pattern: &'a str, is_positive: bool) -> Self {
+    self.string_bounds.push(StringBounds::Regex(pattern, is_positive));
+    self.to_owned()
+  }
+
   // Add a rule set defined via bounds_builder() with ann logic
   // All must match to return true
   pub fn and(&mut self, rules: BoundsBuilder<'a>) -> Self {
@@ -317,6 +402,21 @@ impl<'a> BoundsBuilder<'a> {
     self.to_owned()
   }
 
+  pub fn or_contains_word(&mut self, patterns: &'a [&str], case_mode: CaseMatchMode) -> Self {
+    self.or_true(patterns, case_mode, BoundsPosition::Word);
+    self.to_owned()
+  }
+
+  pub fn or_containing_word_ci(&mut self, patterns: &'a [&str]) -> Self {
+    self.or_contains_word(patterns, CaseMatchMode::Insensitive);
+    self.to_owned()
+  }
+
+  pub fn or_containing_word_cs(&mut self, patterns: &'a [&str]) -> Self {
+    self.or_contains_word(patterns, CaseMatchMode::Sensitive);
+    self.to_owned()
+  }
+
   pub fn or_ends_with(&mut self, patterns: &'a [&str], case_mode: CaseMatchMode) -> Self {
     self.or_true(patterns, case_mode, BoundsPosition::Ends);
     self.to_owned()
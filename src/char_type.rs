@@ -12,7 +12,11 @@ pub enum CharType<'a> {
   Lower,
   Alpha,
   Spaces,
+  HorizontalSpace, // spaces and tabs, but not line breaks
+  Newline, // line feeds \n and carriage returns \r
   Punctuation,
+  UnicodePunctuation, // ASCII punctuation as well as common Unicode punctuation, e.g. « » ¿ … “ ”
+  Emoji, // pictographic emoji, including skin-tone modifiers and regional indicators, but not joiners or variation selectors
   Char(char),
   Chars(&'a [char]),
   Range(Range<char>),
@@ -32,11 +36,151 @@ impl<'a> CharType<'a> {
       Self::Upper => c.is_uppercase(),
       Self::Alpha => c.is_alphabetic(),
       Self::Spaces => c.is_whitespace(),
+      Self::HorizontalSpace => c == ' ' || c == '\t',
+      Self::Newline => c == '\n' || c == '\r',
       Self::Punctuation => c.is_ascii_punctuation(),
+      Self::UnicodePunctuation => is_unicode_punctuation(c),
+      Self::Emoji => is_emoji(c),
       Self::Char(ch) => c == *ch,
       Self::Chars(chars) => chars.contains(&c),
       Self::Range(cr) => cr.contains(&c),
       Self::Between(c1, c2) => c >= *c1 && c <= *c2,
     }
   }
-}
\ No newline at end of file
+}
+
+/// Match characters in the main emoji blocks, including skin-tone modifiers and regional indicators used in flags.
+/// As with is_unicode_punctuation(), this approximates the Unicode Emoji property with ranges rather than lookup tables,
+/// so some symbols in the miscellaneous symbols and dingbats blocks without emoji presentation are matched too
+pub(crate) fn is_emoji(c: char) -> bool {
+  matches!(c,
+    '\u{1F000}'..='\u{1F02F}' // mahjong tiles
+    | '\u{1F0A0}'..='\u{1F0FF}' // playing cards
+    | '\u{1F170}'..='\u{1F251}' // enclosed alphanumerics and ideographs, including regional indicators
+    | '\u{1F300}'..='\u{1FAFF}' // pictographs, emoticons, transport, skin-tone modifiers and later additions
+    | '\u{2600}'..='\u{27BF}' // miscellaneous symbols and dingbats
+    | '\u{2B05}'..='\u{2B07}' | '\u{2B1B}' | '\u{2B1C}' | '\u{2B50}' | '\u{2B55}'
+    | '\u{231A}' | '\u{231B}' | '\u{23E9}'..='\u{23F3}' | '\u{23F8}'..='\u{23FA}'
+    | '\u{3030}' | '\u{303D}' | '\u{3297}' | '\u{3299}'
+  )
+}
+
+/// Match invisible characters that combine emoji into sequences: zero-width joiners, variation selectors,
+/// the combining keycap and tag characters used in subdivision flags
+pub(crate) fn is_emoji_component(c: char) -> bool {
+  matches!(c, '\u{200D}' | '\u{FE0E}' | '\u{FE0F}' | '\u{20E3}' | '\u{E0020}'..='\u{E007F}')
+}
+
+/// Built-in character types without parameters, in declaration order, as checked by classify_char()
+const BUILT_IN_CHAR_TYPES: [CharType<'static>; 12] = [
+  CharType::DecDigit,
+  CharType::Numeric,
+  CharType::AlphaNum,
+  CharType::Upper,
+  CharType::Lower,
+  CharType::Alpha,
+  CharType::Spaces,
+  CharType::HorizontalSpace,
+  CharType::Newline,
+  CharType::Punctuation,
+  CharType::UnicodePunctuation,
+  CharType::Emoji,
+];
+
+/// Return the built-in character types a character belongs to, e.g. [AlphaNum, Upper, Alpha] for 'A'.
+/// CharType::Any and custom types that need parameters, such as Digit(radix) or Chars, are not included
+pub fn classify_char(c: char) -> Vec<CharType<'static>> {
+  BUILT_IN_CHAR_TYPES.iter().filter(|ct| ct.is_in_range(&c)).cloned().collect()
+}
+
+/// Match ASCII punctuation or characters in the main Unicode punctuation ranges.
+/// This approximates the Unicode general punctuation categories without lookup tables
+/// and covers Latin-1, general and supplemental punctuation as well as CJK and full-width punctuation
+pub(crate) fn is_unicode_punctuation(c: char) -> bool {
+  c.is_ascii_punctuation() || matches!(c,
+    '¡' | '§' | '«' | '¶' | '·' | '»' | '¿' | '\u{37E}' | '\u{387}'
+    | '\u{2010}'..='\u{2027}'
+    | '\u{2030}'..='\u{205E}'
+    | '\u{2E00}'..='\u{2E7F}'
+    | '\u{3001}'..='\u{3003}'
+    | '\u{3008}'..='\u{3011}'
+    | '\u{3014}'..='\u{301F}'
+    | '\u{FE10}'..='\u{FE19}'
+    | '\u{FE30}'..='\u{FE4F}'
+    | '\u{FF01}'..='\u{FF0F}'
+    | '\u{FF1A}'..='\u{FF20}'
+    | '\u{FF3B}'..='\u{FF40}'
+    | '\u{FF5B}'..='\u{FF65}'
+  )
+}
+
+/// Methods to extract string slices at the boundaries of a string by character type
+pub trait SliceByCharType {
+  /// Return the longest prefix whose characters all match the character type,
+  /// e.g. "123" from "123abc" with CharType::DecDigit, or an empty string
+  fn leading_run(&self, ct: CharType) -> &str;
+
+  /// Return the longest suffix whose characters all match the character type,
+  /// e.g. "abc" from "123abc" with CharType::Alpha, or an empty string
+  fn trailing_run(&self, ct: CharType) -> &str;
+
+  /// Remove leading characters matching the character type,
+  /// e.g. "hello!!!" from "...hello!!!" with CharType::Punctuation
+  fn trim_start_by_type(&self, ct: CharType) -> &str;
+
+  /// Remove trailing characters matching the character type,
+  /// e.g. "...hello" from "...hello!!!" with CharType::Punctuation
+  fn trim_end_by_type(&self, ct: CharType) -> &str;
+
+  /// Remove leading and trailing characters matching the character type,
+  /// e.g. "hello" from "...hello!!!" with CharType::Punctuation
+  fn trim_by_type(&self, ct: CharType) -> &str;
+
+  /// Return the byte offsets of all characters matching the character type,
+  /// e.g. [1, 3, 5] for "a1b2c3" with CharType::DecDigit
+  fn indices_by_type(&self, ct: CharType) -> Vec<usize>;
+
+  /// Return (character index, byte offset) pairs of all characters matching the character type,
+  /// e.g. [(1, 2)] for "é1" with CharType::DecDigit, as byte offsets differ from character positions in multibyte text
+  fn char_indices_by_type(&self, ct: CharType) -> Vec<(usize, usize)>;
+}
+
+impl SliceByCharType for str {
+
+  fn leading_run(&self, ct: CharType) -> &str {
+    let end = self.find(|c: char| !ct.is_in_range(&c)).unwrap_or(self.len());
+    &self[..end]
+  }
+
+  fn trailing_run(&self, ct: CharType) -> &str {
+    let start = self.char_indices().rev()
+      .take_while(|(_, c)| ct.is_in_range(c))
+      .last()
+      .map_or(self.len(), |(index, _)| index);
+    &self[start..]
+  }
+
+  fn trim_start_by_type(&self, ct: CharType) -> &str {
+    self.trim_start_matches(|c: char| ct.is_in_range(&c))
+  }
+
+  fn trim_end_by_type(&self, ct: CharType) -> &str {
+    self.trim_end_matches(|c: char| ct.is_in_range(&c))
+  }
+
+  fn trim_by_type(&self, ct: CharType) -> &str {
+    self.trim_matches(|c: char| ct.is_in_range(&c))
+  }
+
+  fn indices_by_type(&self, ct: CharType) -> Vec<usize> {
+    self.char_indices().filter(|(_, c)| ct.is_in_range(c)).map(|(index, _)| index).collect()
+  }
+
+  fn char_indices_by_type(&self, ct: CharType) -> Vec<(usize, usize)> {
+    self.char_indices().enumerate()
+      .filter(|(_, (_, c))| ct.is_in_range(c))
+      .map(|(char_index, (byte_index, _))| (char_index, byte_index))
+      .collect()
+  }
+
+}
@@ -0,0 +1,69 @@
+// Lookup tables to strip diacritics from Latin letters without external Unicode normalization crates
+
+/// Map a precomposed Latin letter with a diacritic to its base letter in the same case, e.g. 'é' to 'e' or 'Ł' to 'L'.
+/// Covers the Latin-1 Supplement and Latin Extended-A blocks. Returns None for other characters
+pub(crate) fn base_letter(c: char) -> Option<char> {
+  let base = match c {
+    'À'..='Å' | 'à'..='å' | '\u{100}'..='\u{105}' => 'a',
+    'Ç' | 'ç' | '\u{106}'..='\u{10D}' => 'c',
+    'Ð' | 'ð' | '\u{10E}'..='\u{111}' => 'd',
+    'È'..='Ë' | 'è'..='ë' | '\u{112}'..='\u{11B}' => 'e',
+    '\u{11C}'..='\u{123}' => 'g',
+    '\u{124}'..='\u{127}' => 'h',
+    'Ì'..='Ï' | 'ì'..='ï' | '\u{128}'..='\u{131}' => 'i',
+    '\u{134}'..='\u{135}' => 'j',
+    '\u{136}'..='\u{137}' => 'k',
+    '\u{139}'..='\u{142}' => 'l',
+    'Ñ' | 'ñ' | '\u{143}'..='\u{148}' => 'n',
+    'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | '\u{14C}'..='\u{151}' => 'o',
+    '\u{154}'..='\u{159}' => 'r',
+    '\u{15A}'..='\u{161}' => 's',
+    '\u{162}'..='\u{167}' => 't',
+    'Ù'..='Ü' | 'ù'..='ü' | '\u{168}'..='\u{173}' => 'u',
+    '\u{174}'..='\u{175}' => 'w',
+    'Ý' | 'ý' | 'ÿ' | '\u{176}'..='\u{178}' => 'y',
+    '\u{179}'..='\u{17E}' => 'z',
+    _ => return None
+  };
+  Some(if c.is_uppercase() { base.to_ascii_uppercase() } else { base })
+}
+
+/// Expand ligatures and letters without a single-letter base, e.g. 'æ' to "ae" or 'ß' to "ss"
+pub(crate) fn expand_ligature(c: char) -> Option<&'static str> {
+  match c {
+    'Æ' => Some("AE"),
+    'æ' => Some("ae"),
+    'Œ' => Some("OE"),
+    'œ' => Some("oe"),
+    '\u{132}' => Some("IJ"),
+    '\u{133}' => Some("ij"),
+    'ß' => Some("ss"),
+    _ => None
+  }
+}
+
+/// Combining diacritical marks used in decomposed strings, e.g. "e\u{301}" for "é"
+pub(crate) fn is_combining_mark(c: char) -> bool {
+  matches!(c, '\u{300}'..='\u{36F}')
+}
+
+/// Remove diacritics from precomposed and decomposed Latin letters and expand common ligatures
+pub(crate) fn strip_diacritics(txt: &str) -> String {
+  let mut output = String::with_capacity(txt.len());
+  for c in txt.chars() {
+    if is_combining_mark(c) {
+      continue;
+    }
+    if let Some(expanded) = expand_ligature(c) {
+      output.push_str(expanded);
+    } else {
+      output.push(base_letter(c).unwrap_or(c));
+    }
+  }
+  output
+}
+
+/// Lower-case and strip diacritics for accent- and case-insensitive comparison
+pub(crate) fn fold_for_match(txt: &str) -> String {
+  strip_diacritics(&txt.to_lowercase())
+}
@@ -1,4 +1,5 @@
 use std::str::Chars;
+use crate::{CommentStyle, EncloseError, SimpleMatch, ToTokens};
 
 
 /// Traits with extension emthods to wrap strings in bounding characters
@@ -28,6 +29,12 @@ pub trait SimpleEnclose {
     self.enclose_in_chars(start, end, None, Some('\\'))
   }
 
+  /// Enclose in a start and an end character escaping every occurrence of the end character
+  /// as well as any additional characters such as the escape character itself.
+  /// Unlike enclose_escaped, pre-escaped sequences are not detected, so the content is treated as raw text,
+  /// e.g. escaping both " and \ yields a valid double-quoted string literal
+  fn enclose_escaping_set(& self, start: char, end: char, escape: char, also_escape: &[char]) -> String;
+
   /// Wrap a string in a pair of characters, with the closing character matching the first character
   /// if it a parenthesis (round bracket), angle bracket, (square)  bracket or curly brace. Otherwise
   /// the closing character will be the same opening character
@@ -68,6 +75,37 @@ pub trait SimpleEnclose {
     self.enclose_in_chars(opening, end, None, Some('\\'))
   }
 
+  /// Wrap a string in a pair of characters as with wrap() only if the predicate is true,
+  /// otherwise return the string unchanged
+  fn wrap_if(&self, opening: char, predicate: impl Fn(&str) -> bool) -> String;
+
+  /// Wrap in double quotes only if the string contains the separator, a double quote or whitespace,
+  /// e.g. for CSV values or command arguments. Inner double quotes are escaped with a backslash as with wrap_safe()
+  fn quote_if_needed(&self, separator: char) -> String;
+
+  /// Wrap each word, as extracted by to_words(), in a pair of characters as with wrap() and join them with single spaces,
+  /// e.g. "'red' 'green' 'blue'" from "red, green, blue" with '\''. Punctuation around words is dropped
+  fn wrap_each_word(&self, opening: char) -> String;
+
+  /// Prepend the prefix only if the string does not already start with it, e.g. "/path" from "path" or "/path"
+  fn ensure_prefix(&self, prefix: &str) -> String;
+
+  /// Append the suffix only if the string does not already end with it, e.g. "path/" from "path" or "path/"
+  fn ensure_suffix(&self, suffix: &str) -> String;
+
+  /// Variant of ensure_prefix() that treats an existing prefix in any letter case as present,
+  /// e.g. "HTTPS://example.com" is unchanged with "https://"
+  fn ensure_prefix_ci(&self, prefix: &str) -> String;
+
+  /// Variant of ensure_suffix() that treats an existing suffix in any letter case as present,
+  /// e.g. "photo.JPG" is unchanged with ".jpg"
+  fn ensure_suffix_ci(&self, suffix: &str) -> String;
+
+  /// Enclose in a start and an end character as with enclose(), but return an error rather than wrapping
+  /// if the content contains an end character not escaped by a backslash \, which would close the enclosed string early.
+  /// Callers may then escape explicitly with enclose_escaped() or enclose_safe()
+  fn try_enclose(& self, start: char, end: char) -> Result<String, EncloseError>;
+
   /// wrap in parentheses (sound brackets) with an optional prefix before the main content
   fn in_parentheses(& self, prefix: Option<&str>) -> String {
     self.enclose_in_chars('(', ')', prefix, None)
@@ -103,6 +141,46 @@ pub trait SimpleEnclose {
     self.wrap_escaped('\'', Some('\\'))
   }
 
+  /// Wrap each line of a multi-line string in the start and end strings, e.g. to quote or comment every line
+  /// The original line terminators (\n or \r\n) are preserved and blank lines are left unwrapped
+  fn enclose_each_line(& self, start: &str, end: &str) -> String;
+
+  /// Remove one surrounding pair of identical single or double quotes, e.g. hi from "hi" or 'hi',
+  /// leaving mismatched quotes such as "hi' or unquoted input untouched
+  fn strip_matching_quotes(& self) -> &str {
+    self.strip_matching_quotes_of(&['"', '\''])
+  }
+
+  /// Remove one surrounding pair of identical quote characters from the specified set, e.g. ['"', '\'', '`'] to include backticks
+  fn strip_matching_quotes_of(& self, quotes: &[char]) -> &str;
+
+  /// Remove one surrounding pair of identical single or double quotes as with strip_matching_quotes()
+  /// and unescape doubled inner quotes of the same kind as in CSV, e.g. say "hi" from "say ""hi"""
+  fn strip_matching_quotes_doubled(& self) -> String;
+
+  /// Prefix every line, including blank lines, with the prefix, preserving the original line endings,
+  /// e.g. "> a\n> b" from "a\nb" with "> ". Unlike indentation the prefix need not be whitespace
+  fn prefix_lines(& self, prefix: &str) -> String;
+
+  /// Comment out the text for generated code. Line styles prefix each line with the marker and a space,
+  /// or just the marker for blank lines. The block style places /* and */ on their own lines,
+  /// using the text's line ending, and breaks up any inner */ as "* /" so the comment cannot end early
+  fn comment_block(& self, style: CommentStyle) -> String;
+
+  /// Return the deepest level of nesting of the opening and closing characters,
+  /// e.g. Some(2) for "a(b(c)d)e" with parentheses and Some(0) if neither character is present.
+  /// Returns None if the characters are unbalanced, i.e. a closing character without a preceding opening character
+  /// or unclosed opening characters. If the opening and closing characters are the same, they alternate
+  fn max_nesting_depth(& self, open: char, close: char) -> Option<usize>;
+
+  /// Remove escape characters that precede another character, e.g. \" becomes " and \\ becomes \
+  /// This reverses enclose_safe(), wrap_escaped() and related methods once the enclosing characters are removed.
+  /// A final unpaired escape character is kept
+  fn unescape(& self, escape: char) -> String;
+
+  /// Remove a surrounding pair of matching single or double quotes, if present, and unescape the content
+  fn strip_quotes_unescaped(& self, escape: char) -> String;
+
 }
 
 
@@ -126,6 +204,179 @@ impl SimpleEnclose for str {
     out.push(end);
     out
   }
+
+  fn enclose_each_line(&self, start: &str, end: &str) -> String {
+    let mut out = String::with_capacity(self.len());
+    for line in self.split_inclusive('\n') {
+      let content = line.trim_end_matches(['\r', '\n']);
+      if content.trim().is_empty() {
+        out.push_str(line);
+      } else {
+        out.push_str(start);
+        out.push_str(content);
+        out.push_str(end);
+        out.push_str(&line[content.len()..]);
+      }
+    }
+    out
+  }
+
+  fn strip_matching_quotes_of(&self, quotes: &[char]) -> &str {
+    quotes.iter()
+      .find_map(|&quote| self.strip_prefix(quote).and_then(|rest| rest.strip_suffix(quote)))
+      .unwrap_or(self)
+  }
+
+  fn strip_matching_quotes_doubled(&self) -> String {
+    let inner = self.strip_matching_quotes();
+    match self.chars().next() {
+      Some(quote) if inner.len() < self.len() => inner.replace(&quote.to_string().repeat(2), &quote.to_string()),
+      _ => inner.to_string()
+    }
+  }
+
+  fn prefix_lines(&self, prefix: &str) -> String {
+    let mut out = String::with_capacity(self.len());
+    for line in self.split_inclusive('\n') {
+      out.push_str(prefix);
+      out.push_str(line);
+    }
+    out
+  }
+
+  fn comment_block(&self, style: CommentStyle) -> String {
+    if let Some(marker) = style.line_marker() {
+      let mut out = String::with_capacity(self.len());
+      for line in self.split_inclusive('\n') {
+        out.push_str(marker);
+        if !line.trim().is_empty() {
+          out.push(' ');
+        }
+        out.push_str(line);
+      }
+      out
+    } else {
+      let newline = if self.contains("\r\n") { "\r\n" } else { "\n" };
+      let content = self.replace("*/", "* /");
+      let final_newline = if content.is_empty() || content.ends_with('\n') { "" } else { newline };
+      ["/*", newline, &content, final_newline, "*/"].concat()
+    }
+  }
+
+  fn max_nesting_depth(&self, open: char, close: char) -> Option<usize> {
+    let mut depth: usize = 0;
+    let mut max_depth: usize = 0;
+    for ch in self.chars() {
+      if ch == open && (open != close || depth == 0) {
+        depth += 1;
+        max_depth = max_depth.max(depth);
+      } else if ch == close {
+        depth = depth.checked_sub(1)?;
+      }
+    }
+    if depth == 0 {
+      Some(max_depth)
+    } else {
+      None
+    }
+  }
+
+  fn unescape(&self, escape: char) -> String {
+    let mut out = String::with_capacity(self.len());
+    let mut chars = self.chars();
+    while let Some(ch) = chars.next() {
+      if ch == escape {
+        out.push(chars.next().unwrap_or(ch));
+      } else {
+        out.push(ch);
+      }
+    }
+    out
+  }
+
+  fn strip_quotes_unescaped(&self, escape: char) -> String {
+    let inner = ['"', '\''].into_iter()
+      .find_map(|quote| self.strip_prefix(quote).and_then(|rest| rest.strip_suffix(quote)))
+      .unwrap_or(self);
+    inner.unescape(escape)
+  }
+
+  fn wrap_if(&self, opening: char, predicate: impl Fn(&str) -> bool) -> String {
+    if predicate(self) {
+      self.wrap(opening)
+    } else {
+      self.to_string()
+    }
+  }
+
+  fn quote_if_needed(&self, separator: char) -> String {
+    if self.contains(|c: char| c == separator || c == '"' || c.is_whitespace()) {
+      self.wrap_safe('"')
+    } else {
+      self.to_string()
+    }
+  }
+
+  fn wrap_each_word(&self, opening: char) -> String {
+    self.to_words().iter().map(|word| word.wrap(opening)).collect::<Vec<String>>().join(" ")
+  }
+
+  fn ensure_prefix(&self, prefix: &str) -> String {
+    if self.starts_with(prefix) {
+      self.to_string()
+    } else {
+      [prefix, self].concat()
+    }
+  }
+
+  fn ensure_suffix(&self, suffix: &str) -> String {
+    if self.ends_with(suffix) {
+      self.to_string()
+    } else {
+      [self, suffix].concat()
+    }
+  }
+
+  fn ensure_prefix_ci(&self, prefix: &str) -> String {
+    if self.starts_with_ci(prefix) {
+      self.to_string()
+    } else {
+      [prefix, self].concat()
+    }
+  }
+
+  fn ensure_suffix_ci(&self, suffix: &str) -> String {
+    if self.ends_with_ci(suffix) {
+      self.to_string()
+    } else {
+      [self, suffix].concat()
+    }
+  }
+
+  fn try_enclose(&self, start: char, end: char) -> Result<String, EncloseError> {
+    let mut escaped = false;
+    for (index, ch) in self.char_indices() {
+      if ch == end && !escaped {
+        return Err(EncloseError::UnescapedEnd { end, index });
+      }
+      // a backslash escapes the next character unless it is escaped itself
+      escaped = ch == '\\' && !escaped;
+    }
+    Ok(self.enclose(start, end))
+  }
+
+  fn enclose_escaping_set(&self, start: char, end: char, escape: char, also_escape: &[char]) -> String {
+    let mut out = String::with_capacity(self.len() + 2);
+    out.push(start);
+    for ch in self.chars() {
+      if ch == end || also_escape.contains(&ch) {
+        out.push(escape);
+      }
+      out.push(ch);
+    }
+    out.push(end);
+    out
+  }
 }
 
 /// Escape a string enclosed in (double) quotes.
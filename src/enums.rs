@@ -1,3 +1,5 @@
+use crate::CharType;
+
 /// Defines the matching bounds of simple string matches with case-insensitive/sensitive variants
 /// and accepting the string pattern and positivity flag as arguments
 #[derive(Debug, Clone)]
@@ -6,6 +8,10 @@ pub enum StringBounds<'a> {
   EndsWith(&'a str, bool, CaseMatchMode),
   Contains(&'a str, bool, CaseMatchMode),
   Whole(&'a str, bool, CaseMatchMode),
+  ContainsWord(&'a str, bool, CaseMatchMode),
+  HasType(CharType<'a>, bool), // contains at least one character of the type, or none if negative
+  #[cfg(feature = "regex")]
+  Regex(&'a str, bool), // matches the regular expression, or does not if negative
   And(Vec<StringBounds<'a>>),
   Or(Vec<StringBounds<'a>>)
 }
@@ -19,13 +25,15 @@ impl<'a> StringBounds<'a> {
       BoundsPosition::Starts =>  Self::StartsWith(txt, is_positive, case_mode),
       BoundsPosition::Ends => Self::EndsWith(txt, is_positive, case_mode),
       BoundsPosition::Whole => Self::Whole(txt, is_positive, case_mode),
+      BoundsPosition::Word => Self::ContainsWord(txt, is_positive, case_mode),
       _ => Self::Contains(txt, is_positive, case_mode),
     }
   }
 
   pub fn case_insensitive(&self) -> bool {
     match self {
-      Self::StartsWith(_, _, cm) | Self::EndsWith(_, _, cm) | Self::Contains(_, _, cm) | Self::Whole(_, _, cm) => {
+      Self::StartsWith(_, _, cm) | Self::EndsWith(_, _, cm) | Self::Contains(_, _, cm) | Self::Whole(_, _, cm) |
+      Self::ContainsWord(_, _, cm) => {
         match cm {
           CaseMatchMode::Sensitive => false,
           _ => true,
@@ -37,7 +45,8 @@ impl<'a> StringBounds<'a> {
 
   pub fn case_mode(&self) -> CaseMatchMode {
     match self {
-      Self::StartsWith(_, _, cm) | Self::EndsWith(_, _, cm) | Self::Contains(_, _, cm) | Self::Whole(_, _, cm) => {
+      Self::StartsWith(_, _, cm) | Self::EndsWith(_, _, cm) | Self::Contains(_, _, cm) | Self::Whole(_, _, cm) |
+      Self::ContainsWord(_, _, cm) => {
         *cm
       },
       _ => CaseMatchMode::Sensitive, 
@@ -47,8 +56,11 @@ impl<'a> StringBounds<'a> {
   pub fn pattern(&self) -> &'a str {
     match self {
       Self::StartsWith(txt, _, _) | Self::EndsWith(txt, _, _) |
-      Self::Contains(txt, _, _) | Self::Whole(txt, _, _)
+      Self::Contains(txt, _, _) | Self::Whole(txt, _, _) |
+      Self::ContainsWord(txt, _, _)
       => txt,
+      #[cfg(feature = "regex")]
+      Self::Regex(txt, _) => txt,
       _ => &""
     }.to_owned()
   }
@@ -56,7 +68,10 @@ impl<'a> StringBounds<'a> {
   pub fn is_positive(&self) -> bool {
     match self {
       Self::StartsWith(_, is_pos, _) | Self::EndsWith(_, is_pos, _) |
-      Self::Contains(_, is_pos, _) | Self::Whole(_, is_pos, _) => is_pos,
+      Self::Contains(_, is_pos, _) | Self::Whole(_, is_pos, _) |
+      Self::ContainsWord(_, is_pos, _) | Self::HasType(_, is_pos) => is_pos,
+      #[cfg(feature = "regex")]
+      Self::Regex(_, is_pos) => is_pos,
       _ => &false,
     }.to_owned()
   }
@@ -82,8 +97,82 @@ impl<'a> StringBounds<'a> {
     }
   }
 
+  pub fn contains_word(&self) -> bool {
+    matches!(self, Self::ContainsWord(..))
+  }
+
+}
+
+
+/// Errors surfaced by the fallible try_match_*_conditional() methods when a rule cannot be compiled.
+/// Scalar rules such as StartsWith or Contains never fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchError {
+  InvalidPattern(String), // the offending pattern of a Regex rule, which would otherwise never match
 }
 
+impl std::fmt::Display for MatchError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidPattern(pattern) => write!(f, "the pattern {} is not a valid regular expression", pattern),
+    }
+  }
+}
+
+impl std::error::Error for MatchError {}
+
+/// Errors returned by try_to_segment() explaining why a segment could not be extracted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentError {
+  NoSegments, // the string is empty or contains only separators
+  IndexOutOfRange { index: i32, count: usize }, // the requested index with the number of available segments
+}
+
+impl std::fmt::Display for SegmentError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::NoSegments => write!(f, "the string has no non-empty segments"),
+      Self::IndexOutOfRange { index, count } => write!(f, "segment index {} is out of range for {} segments", index, count),
+    }
+  }
+}
+
+impl std::error::Error for SegmentError {}
+
+/// Errors returned by try_enclose() when the content would break the enclosing characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncloseError {
+  UnescapedEnd { end: char, index: usize }, // the closing character and its byte offset in the content
+}
+
+impl std::fmt::Display for EncloseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::UnescapedEnd { end, index } => write!(f, "the content contains the closing character {} at byte {}", end, index),
+    }
+  }
+}
+
+impl std::error::Error for EncloseError {}
+
+/// Comment syntax used by comment_block() for generated code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentStyle {
+  DoubleSlash, // each line prefixed with //, as in Rust, C++ or JavaScript
+  Hash, // each line prefixed with #, as in shell scripts, Python or YAML
+  Block, // the whole text wrapped in /* and */ on separate lines
+}
+
+impl CommentStyle {
+  /// The marker prefixed to each line for line comment styles
+  pub fn line_marker(&self) -> Option<&'static str> {
+    match self {
+      Self::DoubleSlash => Some("//"),
+      Self::Hash => Some("#"),
+      Self::Block => None,
+    }
+  }
+}
 
 /// Simple enum to define position only, unlinke StringBounds methods with patterns and matching options
 #[derive(Debug, Clone, Copy)]
@@ -91,7 +180,8 @@ pub enum BoundsPosition {
   Starts,
   Ends,
   Contains,
-  Whole
+  Whole,
+  Word // contains the pattern as a whole word, not preceded or followed by letters or digits
 }
 
 /// Core matching mode corresponding to function name suffixes (_cs, _ci and _ci_alphanum)
@@ -100,6 +190,8 @@ pub enum CaseMatchMode {
   Sensitive,
   Insensitive,
   AlphanumInsensitive,
+  Normalized, // case- and accent-insensitive, e.g. "Résumé" matches "resume"
+  AsciiInsensitive, // folds only ASCII letters without allocation, e.g. "ABC" matches "abc", but "É" does not match "é"
 }
 
 impl CaseMatchMode {
@@ -1,4 +1,5 @@
 mod utils;
+mod diacritics;
 pub mod enums;
 pub mod alphanumeric;
 pub mod segments;
@@ -7,6 +8,11 @@ pub mod enclose;
 pub mod to_strings;
 pub mod char_type;
 pub mod bounds_builder;
+pub mod tokens;
+pub mod number_format;
+pub mod similarity;
+pub mod transform;
+pub mod bool_strings;
 
 /// This library provides a set of traits and extension methods for &str and/or String
 /// to facilitate common string manipulations routines that may otherwise require multiple steps
@@ -27,4 +33,14 @@ pub use crate::enclose::*;
 pub use crate::to_strings::*;
 pub use crate::char_type::*;
 /// rules builder
-pub use crate::bounds_builder::*;
\ No newline at end of file
+pub use crate::bounds_builder::*;
+/// Detect and extract structured tokens such as email addresses
+pub use crate::tokens::*;
+/// Decimal and grouping separator definitions for numeric strings
+pub use crate::number_format::*;
+/// Compare strings for shared prefixes, suffixes or substrings
+pub use crate::similarity::*;
+/// Rearrange the characters of strings
+pub use crate::transform::*;
+/// Interpret boolean-like strings
+pub use crate::bool_strings::*;
@@ -0,0 +1,68 @@
+use std::num::ParseIntError;
+
+/// Defines the decimal and optional thousands-grouping separators of numeric strings
+/// e.g. 1,999.50 has a dot as decimal separator and a comma as grouping separator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+  pub decimal_separator: char,
+  pub grouping_separator: Option<char>,
+}
+
+impl NumberFormat {
+  pub fn new(decimal_separator: char, grouping_separator: Option<char>) -> Self {
+    NumberFormat {
+      decimal_separator,
+      grouping_separator
+    }
+  }
+
+  /// Dots as decimal separators and commas as thousand separators, e.g. 1,999.50
+  pub fn standard() -> Self {
+    NumberFormat::new('.', Some(','))
+  }
+
+  /// Commas as decimal separators and dots as thousand separators, e.g. 1.999,50
+  pub fn euro() -> Self {
+    NumberFormat::new(',', Some('.'))
+  }
+
+  /// Returns true if commas are used as decimal separators
+  pub fn has_decimal_comma(&self) -> bool {
+    self.decimal_separator == ','
+  }
+
+  /// Check if the character is the grouping separator.
+  /// A space also matches non-breaking, narrow non-breaking and thin spaces used in French and SI formatting
+  pub fn is_grouping_char(&self, c: char) -> bool {
+    match self.grouping_separator {
+      Some(' ') => matches!(c, ' ' | '\u{A0}' | '\u{202F}' | '\u{2009}'),
+      Some(separator) => c == separator,
+      None => false
+    }
+  }
+}
+
+impl Default for NumberFormat {
+  fn default() -> Self {
+    NumberFormat::standard()
+  }
+}
+
+/// Parse integers from strings of digits in a given base as implemented by from_str_radix() on the primitive integer types
+pub trait FromStrRadix: Sized {
+  fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+  ($($t:ty),*) => {
+    $(
+      impl FromStrRadix for $t {
+        fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+          <$t>::from_str_radix(src, radix)
+        }
+      }
+    )*
+  };
+}
+
+impl_from_str_radix!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -1,4 +1,25 @@
-use crate::{simple_match::*, utils::extract_string_element_by_index};
+use crate::{simple_match::*, utils::{capitalize_first, extract_string_element_by_index, find_ci}, CharType, SegmentError, StripCharacters};
+
+/// Options to compose split behaviours with to_split()
+/// trim: trim whitespace from each part
+/// drop_empty: skip empty parts, after trimming if enabled
+/// limit: maximum number of parts, with the last part containing the unsplit remainder as with splitn()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SplitOptions {
+  pub trim: bool,
+  pub drop_empty: bool,
+  pub limit: Option<usize>,
+}
+
+impl SplitOptions {
+  pub fn new(trim: bool, drop_empty: bool, limit: Option<usize>) -> Self {
+    SplitOptions {
+      trim,
+      drop_empty,
+      limit
+    }
+  }
+}
 
 /// Methods to split a longer strong on a separator and return a vector of strings,
 /// a tuple of two strings or single optional string segment
@@ -6,13 +27,38 @@ use crate::{simple_match::*, utils::extract_string_element_by_index};
 /// See notes below
 pub trait ToSegments {
 
+  /// Split a string on a separator with configurable trimming, removal of empty parts and a maximum number of parts
+  /// to_parts() and to_segments() are presets of this method
+  fn to_split(&self, separator: &str, opts: SplitOptions) -> Vec<String>;
+
   /// Extract a vector of non-empty strings from a string-like object with a given separator
   /// excluding leading, trailing or double separators
   fn to_segments(&self, separator: &str) -> Vec<String>;
 
+  /// Extract non-empty segments with whitespace trimmed and inner runs of whitespace collapsed to single spaces
+  /// as with normalize_whitespace(), e.g. ["a b", "c"] from " a  b ,  , c " with ",". Segments with only whitespace are dropped
+  fn to_segments_normalized(&self, separator: &str) -> Vec<String> {
+    self.to_segments(separator).iter()
+      .map(|segment| segment.normalize_whitespace())
+      .filter(|segment| !segment.is_empty())
+      .collect()
+  }
+
+  /// Extract a vector of non-empty strings as with to_segments(), but ignoring separators between quote characters,
+  /// e.g. `a/"b/c"/d` with "/" and '"' yields ["a", "b/c", "d"]. The quote characters are removed.
+  /// An unterminated quote extends to the end of the string
+  fn to_segments_quoted(&self, separator: &str, quote: char) -> Vec<String>;
+
   /// Extract a vector of strings from a string-like object with a given separator
   fn to_parts(&self, separator: &str) -> Vec<String>;
 
+  /// Count the non-empty segments as returned by to_segments() without allocating them,
+  /// e.g. 5 for "/var/www/mysite.com/web/uploads/" with "/"
+  fn count_segments(&self, separator: &str) -> usize;
+
+  /// Count all parts as returned by to_parts(), including empty parts, without allocating them
+  fn count_parts(&self, separator: &str) -> usize;
+
   /// Extract only the head before the first occurrence of a separator
   fn to_head(&self, separator: &str) -> String;
 
@@ -20,11 +66,18 @@ pub trait ToSegments {
   fn to_first(&self, separator: &str) -> String;
 
   /// Extract only the remainder after the first occurrence of a non-initial separator
+  /// or an empty string if there is no such separator
   fn to_remainder_end(&self, separator: &str) -> String;
 
   /// Extract only the last segment after the last occurrence of a non-final separator
   fn to_last(&self, separator: &str) -> String;
 
+  /// Variant of to_first() that returns None if the separator is absent rather than the whole string
+  fn first_segment_opt(&self, separator: &str) -> Option<String>;
+
+  /// Variant of to_last() that returns None if the separator is absent rather than the whole string
+  fn last_segment_opt(&self, separator: &str) -> Option<String>;
+
   /// Extract only the beginning before the last segment following the last occurrence of a non-final separator
   fn to_remainder_start(&self, separator: &str) -> String;
 
@@ -40,6 +93,17 @@ pub trait ToSegments {
     extract_string_element_by_index(parts, index)
   }
 
+  /// Variant of to_segment() that explains why the segment could not be extracted,
+  /// distinguishing strings without any non-empty segments from indices out of range
+  fn try_to_segment(&self, separator: &str, index: i32) -> Result<String, SegmentError> {
+    let parts = self.to_segments(separator);
+    let count = parts.len();
+    if count == 0 {
+      return Err(SegmentError::NoSegments);
+    }
+    extract_string_element_by_index(parts, index).ok_or(SegmentError::IndexOutOfRange { index, count })
+  }
+
   /// Extract a part identified by its index from the components of a string with a given separator
   /// e.g. String::from("10/11/2024") .to_parts(1) yields "11"
   /// A negative index parameter will start from the end 
@@ -48,35 +112,259 @@ pub trait ToSegments {
     extract_string_element_by_index(parts, index)
   }
 
+  /// Variant of to_segment() returning the default string if there is no segment at the index
+  fn to_segment_or(&self, separator: &str, index: i32, default: &str) -> String {
+    self.to_segment(separator, index).unwrap_or_else(|| default.to_string())
+  }
+
+  /// Replace a contiguous range of parts, as split by to_parts(), with a single value and rejoin them with the separator,
+  /// e.g. "a/b/c/d" with "/", 1, 3 and "X" yields "a/X/d". The end index is exclusive.
+  /// Negative indices count from the end, so -1 is the last part, and indices are clamped to the number of parts.
+  /// If the clamped range is empty, the string is returned unchanged
+  fn replace_segments(&self, separator: &str, start: i32, end: i32, value: &str) -> String {
+    let mut parts = self.to_parts(separator);
+    let num_parts = parts.len() as i32;
+    let to_position = |index: i32| if index < 0 { (num_parts + index).max(0) } else { index.min(num_parts) } as usize;
+    let (start_index, end_index) = (to_position(start), to_position(end));
+    if start_index < end_index {
+      parts.splice(start_index..end_index, [value.to_string()]);
+    }
+    parts.join(separator)
+  }
+
   /// Extract an inner segment via a set of separator + index tuples
   fn to_inner_segment(&self, groups: &[(&str, i32)]) -> Option<String>;
 
-  /// extract the remainder after the head
+  /// Variant of to_inner_segment() returning the default string if the inner segment is not matched
+  fn to_inner_segment_or(&self, groups: &[(&str, i32)], default: &str) -> String {
+    self.to_inner_segment(groups).unwrap_or_else(|| default.to_string())
+  }
+
+  /// extract the remainder after the head or an empty string if the separator is absent
   fn to_tail(&self, separator: &str) -> String;
 
   /// extract the first and last parts after the first occurrence of the separator
+  /// If the separator is absent, the head is the whole string and the tail is empty, as with to_head() and to_tail()
   fn to_head_tail(&self, separator: &str) -> (String, String);
 
+  /// Extract the first n parts split by the separator and the unsplit remainder,
+  /// e.g. (["a", "b"], "c-d") from "a-b-c-d" with "-" and 2. This generalises to_head_tail() with n = 1.
+  /// If there are fewer than n separators, all parts are returned with an empty remainder
+  fn to_head_n(&self, separator: &str, n: usize) -> (Vec<String>, String);
+
   /// extract the first and last parts after the last occurrence of the separator
   fn to_start_end(&self, separator: &str) -> (String, String);
 
+  /// Capitalize the first letter of each non-empty segment and join them with the joiner
+  /// e.g. "user_profile_settings" with "_" and " " yields "User Profile Settings"
+  /// Leading, trailing or repeated separators are ignored
+  fn segments_to_title(&self, separator: &str, joiner: &str) -> String {
+    self.to_segments(separator).iter().map(|segment| capitalize_first(segment)).collect::<Vec<String>>().join(joiner)
+  }
+
+  /// Extract the content between the first occurrence of the opening pattern and the last occurrence of the closing pattern,
+  /// e.g. "func(a, b, (c))" with "(" and ")" yields "a, b, (c)". Returns None if either is missing
+  /// or the closing pattern only occurs before the opening one. Inner pairs are not counted
+  fn between_first_last(&self, open: &str, close: &str) -> Option<String>;
+
+  /// Extract the inner text of each pair of opening and closing delimiters, e.g. ["a", "b"] from "{{a}}{{b}}" with "{{" and "}}".
+  /// Unmatched delimiters are skipped, so an opening delimiter followed by another before its closing one is ignored,
+  /// as is an unterminated opening delimiter. Nested pairs are not supported
+  fn extract_delimited_tokens(&self, open: &str, close: &str) -> Vec<String>;
+
+  /// Remove empty segments caused by leading, trailing or repeated separators,
+  /// e.g. "a/b/c" from "/a//b///c/" with "/"
+  fn collapse_separators(&self, separator: &str) -> String {
+    self.collapse_separators_with(separator, false, false)
+  }
+
+  /// Remove empty segments caused by repeated separators, optionally keeping a single leading or trailing separator
+  /// if the string starts or ends with the separator, e.g. "/a/b/c/" from "/a//b///c/" with "/", true and true
+  fn collapse_separators_with(&self, separator: &str, keep_leading: bool, keep_trailing: bool) -> String;
+
+  /// Reverse the order of parts, as split by to_parts(), and rejoin them with the separator,
+  /// e.g. "com.example.app" with "." yields "app.example.com". Empty parts are kept, so "/a/b" yields "b/a/"
+  fn reverse_parts(&self, separator: &str) -> String {
+    let mut parts = self.to_parts(separator);
+    parts.reverse();
+    parts.join(separator)
+  }
+
+  /// Reverse the order of non-empty segments, as split by to_segments(), and rejoin them with the separator,
+  /// e.g. "/a//b/" with "/" yields "b/a"
+  fn reverse_segments(&self, separator: &str) -> String {
+    let mut segments = self.to_segments(separator);
+    segments.reverse();
+    segments.join(separator)
+  }
+
+  /// Split on the first occurrence of the separator, alias of to_head_tail()
+  fn split_first(&self, separator: &str) -> (String, String) {
+    self.to_head_tail(separator)
+  }
+
+  /// Split on the last occurrence of the separator, alias of to_start_end()
+  fn split_last(&self, separator: &str) -> (String, String) {
+    self.to_start_end(separator)
+  }
+
+  /// Split on the first occurrence of the separator returning string slices without allocation
+  /// If the separator is absent, the first part is the whole string and the second part empty
+  fn split_first_ref(&self, separator: &str) -> (&str, &str);
+
+  /// Split on the last occurrence of the separator returning string slices without allocation
+  /// If the separator is absent, the first part is the whole string and the second part empty
+  fn split_last_ref(&self, separator: &str) -> (&str, &str);
+
+  /// Split a fixed-width record into fields of the given widths in characters, with any remainder as a final field,
+  /// e.g. ["ABC", "12", "XY"] from "ABC12XY" with [3, 2]. If the record is shorter, only the available fields are returned.
+  /// The boolean flag trims trailing padding spaces from each field
+  fn split_fixed_widths_conditional(&self, widths: &[usize], trim_padding: bool) -> Vec<String>;
+
+  /// Split a fixed-width record into fields of the given widths in characters keeping any padding
+  fn split_fixed_widths(&self, widths: &[usize]) -> Vec<String> {
+    self.split_fixed_widths_conditional(widths, false)
+  }
+
+  /// Split a fixed-width record into fields of the given widths in characters, trimming trailing padding spaces
+  fn split_fixed_widths_trimmed(&self, widths: &[usize]) -> Vec<String> {
+    self.split_fixed_widths_conditional(widths, true)
+  }
+
+  /// Return the slice before the first occurrence of the pattern or the whole string if it is absent,
+  /// e.g. "key" from "key: value" with ": ". Unlike to_head() this does not allocate
+  fn take_until(&self, pattern: &str) -> &str;
+
+  /// Return the slice after the first occurrence of the pattern or an empty string if it is absent,
+  /// e.g. "value" from "key: value" with ": "
+  fn take_after(&self, pattern: &str) -> &str;
+
+  /// Variant of take_until() matching the pattern case-insensitively
+  fn take_until_ci(&self, pattern: &str) -> &str;
+
+  /// Variant of take_after() matching the pattern case-insensitively
+  fn take_after_ci(&self, pattern: &str) -> &str;
+
+  /// Split on matches of a regular expression, dropping empty segments as with to_segments(),
+  /// e.g. ["a", "b", "c"] from "a,  b ,c" with `[,\s]+`. An invalid pattern yields the whole string as the only segment
+  #[cfg(feature = "regex")]
+  fn to_segments_regex(&self, pattern: &str) -> Vec<String>;
+
+  /// Variant of to_segments_regex() that returns the compilation error for an invalid pattern
+  #[cfg(feature = "regex")]
+  fn try_to_segments_regex(&self, pattern: &str) -> Result<Vec<String>, regex::Error>;
+
 }
 
 /// Implement string segment split and capture method for String
 impl ToSegments for str {
 
+  /// Split a string on the separator applying the trim, drop_empty and limit options in one pass
+  fn to_split(&self, separator: &str, opts: SplitOptions) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    if opts.limit == Some(0) {
+      return parts;
+    }
+    let mut start = 0;
+    for part in self.split(separator) {
+      let part_start = start;
+      start += part.len() + separator.len();
+      let value = if opts.trim { part.trim() } else { part };
+      if opts.drop_empty && value.is_empty() {
+        continue;
+      }
+      if opts.limit.is_some_and(|limit| parts.len() + 1 == limit) {
+        let remainder = &self[part_start..];
+        parts.push(if opts.trim { remainder.trim() } else { remainder }.to_string());
+        break;
+      }
+      parts.push(value.to_string());
+    }
+    parts
+  }
+
   /// Splits a string on the exact separator, whether initial, final or repeated.
   /// May yield empty segments
   fn to_parts(&self, separator: &str) -> Vec<String> {
-    let splitter = self.split(separator);
-    splitter.into_iter().map(|s| s.to_string()).collect::<Vec<String>>()
+    self.to_split(separator, SplitOptions::default())
   }
 
   /// Splits a string on a separator, but only returns an array of non-empty strings
   /// skipping leading, trailing or repeated separators that may otherwise yield empty strings
   fn to_segments(&self, separator: &str) -> Vec<String> {
-    let splitter = self.split(separator);
-    splitter.into_iter().map(|s| s.to_string()).filter(|s| s.len() > 0).collect::<Vec<String>>()
+    self.to_split(separator, SplitOptions::new(false, true, None))
+  }
+
+  fn extract_delimited_tokens(&self, open: &str, close: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    if open.is_empty() || close.is_empty() {
+      return tokens;
+    }
+    let mut remainder = self;
+    while let Some(start) = remainder.find(open) {
+      let inner_start = &remainder[start + open.len()..];
+      let Some(end) = inner_start.find(close) else {
+        break;
+      };
+      let inner = &inner_start[..end];
+      // restart from a later opening delimiter if the first one is unmatched
+      if let Some(reopen) = inner.rfind(open) {
+        remainder = &inner_start[reopen..];
+        continue;
+      }
+      tokens.push(inner.to_string());
+      remainder = &inner_start[end + close.len()..];
+    }
+    tokens
+  }
+
+  fn collapse_separators_with(&self, separator: &str, keep_leading: bool, keep_trailing: bool) -> String {
+    if separator.is_empty() {
+      return self.to_string();
+    }
+    let inner = self.to_segments(separator).join(separator);
+    let lead = keep_leading && self.starts_with(separator);
+    let trail = keep_trailing && self.ends_with(separator);
+    if inner.is_empty() {
+      // a string made only of separators collapses to a single separator if either edge is kept
+      return if lead || trail { separator.to_string() } else { inner };
+    }
+    [if lead { separator } else { "" }, &inner, if trail { separator } else { "" }].concat()
+  }
+
+  fn count_segments(&self, separator: &str) -> usize {
+    self.split(separator).filter(|part| !part.is_empty()).count()
+  }
+
+  fn count_parts(&self, separator: &str) -> usize {
+    self.split(separator).count()
+  }
+
+  fn to_segments_quoted(&self, separator: &str, quote: char) -> Vec<String> {
+    let mut segments: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = self.char_indices();
+    while let Some((index, c)) = chars.next() {
+      if c == quote {
+        in_quotes = !in_quotes;
+      } else if !in_quotes && !separator.is_empty() && self[index..].starts_with(separator) {
+        if !current.is_empty() {
+          segments.push(std::mem::take(&mut current));
+        }
+        // skip the remaining characters of a multi-character separator
+        let sep_chars = separator.chars().count();
+        if sep_chars > 1 {
+          chars.nth(sep_chars - 2);
+        }
+      } else {
+        current.push(c);
+      }
+    }
+    if !current.is_empty() {
+      segments.push(current);
+    }
+    segments
   }
 
   /// Extract only the head as a string. If the separator is absent return the whole string
@@ -99,6 +387,28 @@ impl ToSegments for str {
     }
   }
 
+  fn between_first_last(&self, open: &str, close: &str) -> Option<String> {
+    let start = self.find(open)? + open.len();
+    let end = self[start..].rfind(close)? + start;
+    Some(self[start..end].to_string())
+  }
+
+  fn first_segment_opt(&self, separator: &str) -> Option<String> {
+    if self.contains(separator) {
+      Some(self.to_first(separator))
+    } else {
+      None
+    }
+  }
+
+  fn last_segment_opt(&self, separator: &str) -> Option<String> {
+    if self.contains(separator) {
+      Some(self.to_last(separator))
+    } else {
+      None
+    }
+  }
+
   /// extract the last segment whether empty or not
   fn to_end(&self, separator: &str) -> String {
     let parts = self.to_parts(separator);
@@ -110,14 +420,12 @@ impl ToSegments for str {
   }
 
   /// extract the remainder after the first split 
-  /// or the whole string if the separator is absent
+  /// or an empty string if the separator is absent, as the head is then the whole string
   fn to_tail(&self, separator: &str) -> String {
-    let parts = self.to_parts(separator);
-    let num_parts = parts.len();
-    if num_parts > 0 {
-      parts[1..num_parts].join(separator)
+    if let Some((_head, tail)) = self.split_once(separator) {
+      tail.to_string()
     } else {
-      self.to_owned()
+      "".to_owned()
     }
   }
 
@@ -174,15 +482,22 @@ impl ToSegments for str {
 
   /// Extract a tuple of the head and remainder
   /// like split_once but returninga tuple of strings
-  /// If the separator is absent or at the start, the first part will be empty
+  /// If the separator is at the start, the first part will be empty
+  /// If the separator is absent, the first part will be the whole string and the second part empty
   fn to_head_tail(&self, separator: &str) -> (String, String) {
     if let Some((head, tail)) = self.split_once(separator) {
       (head.to_string(), tail.to_string())
     } else {
-      ("".to_owned(), self.to_owned())
+      (self.to_owned(), "".to_owned())
     }
   }
 
+  fn to_head_n(&self, separator: &str, n: usize) -> (Vec<String>, String) {
+    let mut parts = self.splitn(n + 1, separator);
+    let heads: Vec<String> = parts.by_ref().take(n).map(|part| part.to_string()).collect();
+    (heads, parts.next().unwrap_or("").to_string())
+  }
+
   /// Extract a tuple of the start and the last part
   /// like split_once in reverse and returning a tuple of strings
   /// If the separator is absent or at the end, the second part will be empty
@@ -194,6 +509,62 @@ impl ToSegments for str {
     }
   }
 
+  fn split_first_ref(&self, separator: &str) -> (&str, &str) {
+    self.split_once(separator).unwrap_or((self, ""))
+  }
+
+  fn split_last_ref(&self, separator: &str) -> (&str, &str) {
+    self.rsplit_once(separator).unwrap_or((self, ""))
+  }
+
+  fn split_fixed_widths_conditional(&self, widths: &[usize], trim_padding: bool) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::with_capacity(widths.len() + 1);
+    let mut remainder = self;
+    for &width in widths {
+      if remainder.is_empty() {
+        break;
+      }
+      let end = remainder.char_indices().nth(width).map_or(remainder.len(), |(index, _)| index);
+      let (field, rest) = remainder.split_at(end);
+      fields.push(field.to_string());
+      remainder = rest;
+    }
+    if !remainder.is_empty() {
+      fields.push(remainder.to_string());
+    }
+    if trim_padding {
+      fields.iter_mut().for_each(|field| field.truncate(field.trim_end_matches(' ').len()));
+    }
+    fields
+  }
+
+  fn take_until(&self, pattern: &str) -> &str {
+    self.split_first_ref(pattern).0
+  }
+
+  fn take_after(&self, pattern: &str) -> &str {
+    self.split_first_ref(pattern).1
+  }
+
+  fn take_until_ci(&self, pattern: &str) -> &str {
+    find_ci(self, pattern).map_or(self, |range| &self[..range.start])
+  }
+
+  fn take_after_ci(&self, pattern: &str) -> &str {
+    find_ci(self, pattern).map_or("", |range| &self[range.end..])
+  }
+
+  #[cfg(feature = "regex")]
+  fn to_segments_regex(&self, pattern: &str) -> Vec<String> {
+    self.try_to_segments_regex(pattern).unwrap_or_else(|_| vec![self.to_string()])
+  }
+
+  #[cfg(feature = "regex")]
+  fn try_to_segments_regex(&self, pattern: &str) -> Result<Vec<String>, regex::Error> {
+    let re = regex::Regex::new(pattern)?;
+    Ok(re.split(self).filter(|part| !part.is_empty()).map(|part| part.to_string()).collect())
+  }
+
 }
 
 
@@ -203,15 +574,35 @@ pub trait ToSegmentsFromChars {
   /// Split a string into parts separated by any of the referenced split characters
   fn split_on_any_char(&self, separators: &[char]) -> Vec<String>;
 
+  /// Split a string on any of the referenced characters pairing each part with the separator that followed it,
+  /// e.g. "a-b_c" on ['-', '_'] yields [("a", Some('-')), ("b", Some('_')), ("c", None)].
+  /// The last part is always paired with None, so the original string may be reconstructed
+  fn split_on_any_char_with_delims(&self, separators: &[char]) -> Vec<(String, Option<char>)>;
+
   /// Split a string into a head and tail separated by the first instance of the first matching separator
   /// If none of the separators are matched, the first element is
-  ///  an empty string and the second the whole string
+  /// the whole string and the second an empty string as with to_head_tail()
   fn to_head_tail_on_any_char(&self, separators: &[char]) -> (String, String);
 
   /// Split a string into s start and tail separated by the last instance of the first matching separator
   /// If none of the separators are matched, the first element is
-  ///  an empty string and the second the whole string
+  /// the whole string and the second an empty string as with to_start_end()
   fn to_start_end_on_any_char(&self, separators: &[char]) -> (String, String);
+
+  /// Split a string into runs of characters wherever the first matching character type changes,
+  /// e.g. "abc123" with [CharType::Alpha, CharType::DecDigit] yields ["abc", "123"].
+  /// Consecutive characters that match none of the types are grouped in their own segments,
+  /// so that joining the segments reproduces the original string
+  fn split_at_type_boundaries(&self, types: &[CharType]) -> Vec<String>;
+
+  /// Split a string after each of the referenced characters, keeping the separator at the end of each segment,
+  /// e.g. "a.b.c" on '.' yields ["a.", "b.", "c"]. A trailing separator does not add an empty segment
+  fn split_keep_trailing(&self, separators: &[char]) -> Vec<String>;
+
+  /// Split a string on any character matching the predicate returning only non-empty runs of other characters,
+  /// e.g. "a1!b2?c" with |c| !c.is_alphanumeric() yields ["a1", "b2", "c"].
+  /// This generalises split_on_any_char() for dynamic separator conditions
+  fn split_where(&self, pred: impl Fn(char) -> bool) -> Vec<String>;
 }
 
 impl ToSegmentsFromChars for str {
@@ -242,6 +633,17 @@ impl ToSegmentsFromChars for str {
     }
   }
 
+  fn split_on_any_char_with_delims(&self, separators: &[char]) -> Vec<(String, Option<char>)> {
+    let mut parts: Vec<(String, Option<char>)> = Vec::new();
+    let mut prev_start = 0;
+    for (index, matched) in self.match_indices(separators) {
+      parts.push((self[prev_start..index].to_string(), matched.chars().next()));
+      prev_start = index + matched.len();
+    }
+    parts.push((self[prev_start..].to_string(), None));
+    parts
+  }
+
   /// Split into head and tail components on the first occurrence of any of the referenced characters
   fn to_head_tail_on_any_char(&self, separators: &[char]) -> (String, String) {
     for ch in separators {
@@ -251,7 +653,7 @@ impl ToSegmentsFromChars for str {
         }
       }
     }
-    ("".to_owned(), self.to_string())
+    (self.to_string(), "".to_owned())
   }
 
   /// Split into start and end components on the last occurrence of any of the referenced characters
@@ -266,4 +668,78 @@ impl ToSegmentsFromChars for str {
     (self.to_string(), "".to_owned())
   }
 
+  fn split_keep_trailing(&self, separators: &[char]) -> Vec<String> {
+    self.split_inclusive(separators).map(|segment| segment.to_string()).collect()
+  }
+
+  fn split_where(&self, pred: impl Fn(char) -> bool) -> Vec<String> {
+    self.split(pred).filter(|part| !part.is_empty()).map(|part| part.to_string()).collect()
+  }
+
+  /// Start a new segment when the index of the first matching character type changes
+  fn split_at_type_boundaries(&self, types: &[CharType]) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut prev_type_index: Option<usize> = None;
+    for ch in self.chars() {
+      let type_index = types.iter().position(|ct| ct.is_in_range(&ch));
+      if type_index != prev_type_index && !current.is_empty() {
+        parts.push(std::mem::take(&mut current));
+      }
+      current.push(ch);
+      prev_type_index = type_index;
+    }
+    if !current.is_empty() {
+      parts.push(current);
+    }
+    parts
+  }
+
+}
+
+/// Methods to access characters by their position rather than byte offset, so multibyte characters cannot be split
+pub trait SliceByCharIndex {
+
+  /// Character at the specified character index or None if out of range
+  /// A negative index counts from the end, e.g. -1 is the last character
+  fn char_at(&self, index: i32) -> Option<char>;
+
+  /// Characters from the start index up to but excluding the end index, e.g. "café".char_range(1, 3) yields "af"
+  /// Negative indices count from the end as with char_at(), so (1, -1) drops the first and last characters.
+  /// Returns None if either index is out of range or the start is after the end
+  fn char_range(&self, start: i32, end: i32) -> Option<String>;
+
+}
+
+impl SliceByCharIndex for str {
+
+  fn char_at(&self, index: i32) -> Option<char> {
+    if index >= 0 {
+      self.chars().nth(index as usize)
+    } else {
+      self.chars().rev().nth(index.unsigned_abs() as usize - 1)
+    }
+  }
+
+  fn char_range(&self, start: i32, end: i32) -> Option<String> {
+    let num_chars = self.chars().count();
+    let start_index = resolve_char_index(start, num_chars)?;
+    let end_index = resolve_char_index(end, num_chars)?;
+    if start_index <= end_index {
+      Some(self.chars().skip(start_index).take(end_index - start_index).collect())
+    } else {
+      None
+    }
+  }
+
+}
+
+/// Convert a possibly negative character index to a position from the start, which may equal the length as an end bound
+fn resolve_char_index(index: i32, num_chars: usize) -> Option<usize> {
+  let position = if index >= 0 { index as usize } else { num_chars.checked_sub(index.unsigned_abs() as usize)? };
+  if position <= num_chars {
+    Some(position)
+  } else {
+    None
+  }
 }
@@ -0,0 +1,106 @@
+
+/// Methods to find the longest shared prefix or suffix of a set of strings,
+/// e.g. a common directory root or filename stem
+pub trait CommonAffixes {
+
+  /// Longest common prefix of all strings compared by character.
+  /// Returns an empty string for an empty set and the whole string if there is only one
+  fn common_prefix(&self) -> String;
+
+  /// Longest common suffix of all strings compared by character.
+  /// Returns an empty string for an empty set and the whole string if there is only one
+  fn common_suffix(&self) -> String;
+
+}
+
+impl<T: AsRef<str>> CommonAffixes for [T] {
+
+  fn common_prefix(&self) -> String {
+    let mut items = self.iter().map(|item| item.as_ref());
+    let mut prefix: Vec<char> = items.next().map(|first| first.chars().collect()).unwrap_or_default();
+    for item in items {
+      let shared_len = prefix.iter().zip(item.chars()).take_while(|(a, b)| *a == b).count();
+      prefix.truncate(shared_len);
+    }
+    prefix.into_iter().collect()
+  }
+
+  fn common_suffix(&self) -> String {
+    let mut items = self.iter().map(|item| item.as_ref());
+    // collect the characters in reverse order to compare from the end
+    let mut suffix: Vec<char> = items.next().map(|first| first.chars().rev().collect()).unwrap_or_default();
+    for item in items {
+      let shared_len = suffix.iter().zip(item.chars().rev()).take_while(|(a, b)| *a == b).count();
+      suffix.truncate(shared_len);
+    }
+    suffix.into_iter().rev().collect()
+  }
+
+}
+
+/// Methods to compare the content of two strings
+pub trait StringSimilarity {
+
+  /// Longest run of characters shared by both strings, e.g. "cde" in "abcdef" and "zcdemf".
+  /// If there are several runs of the same length, the first one in this string is returned
+  fn longest_common_substring(&self, other: &str) -> String;
+
+  /// Levenshtein distance, i.e. the minimum number of single-character insertions, deletions or substitutions
+  /// to turn this string into the other, e.g. 3 for "kitten" and "sitting"
+  fn edit_distance(&self, other: &str) -> usize;
+
+  /// Similarity from 0.0 to 1.0 derived from the edit distance as 1 - distance / the length of the longer string
+  /// in characters, e.g. 0.571 for "kitten" and "sitting". Two empty strings are identical and yield 1.0
+  fn similarity_ratio(&self, other: &str) -> f64;
+
+}
+
+impl StringSimilarity for str {
+
+  /// Dynamic programming over characters keeping only the previous row of distances
+  fn edit_distance(&self, other: &str) -> usize {
+    let other_chars: Vec<char> = other.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=other_chars.len()).collect();
+    let mut row = vec![0usize; other_chars.len() + 1];
+    for (i, c) in self.chars().enumerate() {
+      row[0] = i + 1;
+      for (j, other_c) in other_chars.iter().enumerate() {
+        let substitution_cost = if c == *other_c { 0 } else { 1 };
+        row[j + 1] = (prev_row[j] + substitution_cost).min(prev_row[j + 1] + 1).min(row[j] + 1);
+      }
+      std::mem::swap(&mut prev_row, &mut row);
+    }
+    prev_row[other_chars.len()]
+  }
+
+  fn similarity_ratio(&self, other: &str) -> f64 {
+    let max_len = self.chars().count().max(other.chars().count());
+    if max_len < 1 {
+      1.0
+    } else {
+      1.0 - self.edit_distance(other) as f64 / max_len as f64
+    }
+  }
+
+  /// Dynamic programming over characters keeping only the previous row of run lengths
+  fn longest_common_substring(&self, other: &str) -> String {
+    let chars: Vec<char> = self.chars().collect();
+    let other_chars: Vec<char> = other.chars().collect();
+    let mut prev_row = vec![0usize; other_chars.len() + 1];
+    let mut row = vec![0usize; other_chars.len() + 1];
+    let mut max_len = 0;
+    let mut end_index = 0;
+    for (i, c) in chars.iter().enumerate() {
+      for (j, other_c) in other_chars.iter().enumerate() {
+        row[j + 1] = if c == other_c { prev_row[j] + 1 } else { 0 };
+        if row[j + 1] > max_len {
+          max_len = row[j + 1];
+          end_index = i + 1;
+        }
+      }
+      std::mem::swap(&mut prev_row, &mut row);
+    }
+    chars[end_index - max_len..end_index].iter().collect()
+  }
+
+}
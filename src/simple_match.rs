@@ -1,4 +1,4 @@
-use crate::{enums::StringBounds, utils::{pairs_to_string_bounds, strs_to_string_bounds}, BoundsBuilder, BoundsPosition, CaseMatchMode, CharType, StripCharacters};
+use crate::{enums::{MatchError, StringBounds}, utils::{find_ci, match_ascii_ci_by_position, match_ci_alphanum_by_position, match_ci_by_position, match_cs_by_position, match_normalized_by_position, pairs_to_string_bounds, strs_to_string_bounds}, BoundsBuilder, BoundsPosition, CaseMatchMode, CharType, StripCharacters};
 
 /// Regex-free matcher methods for common use cases
 /// There are no plain and _cs-suffixed variants because the standard
@@ -28,6 +28,20 @@ pub trait SimpleMatch {
   
   /// Contains a case-insensitive alphanumeric sequence
   fn contains_ci_alphanum(&self, pattern: &str) -> bool;
+
+  /// Matches the whole string ignoring case and diacritics, e.g. "Résumé" equals "resume"
+  fn equals_normalized(&self, pattern: &str) -> bool;
+
+  /// Return the first pattern the string starts with in case-insensitive mode or None if there is no match
+  fn matched_prefix_ci<'a>(&self, patterns: &[&'a str]) -> Option<&'a str> {
+    patterns.iter().copied().find(|pattern| self.starts_with_ci(pattern))
+  }
+
+  /// Return the first pattern the string ends with in case-insensitive mode or None if there is no match,
+  /// e.g. ".png" for "image.PNG" with [".jpg", ".png"]
+  fn matched_suffix_ci<'a>(&self, patterns: &[&'a str]) -> Option<&'a str> {
+    patterns.iter().copied().find(|pattern| self.ends_with_ci(pattern))
+  }
 }
 
 /// Implementation for &str/String 
@@ -35,42 +49,48 @@ impl SimpleMatch for str {
 
    /// Starts with a case-insensitive sequence
   fn equals_ci(&self, pattern: &str) -> bool {
-    self.to_lowercase() == pattern.to_lowercase()
+    match_ci_by_position(self, pattern, BoundsPosition::Whole)
   }
   
   /// Starts with a case-insensitive alphanumeric sequence
   fn equals_ci_alphanum(&self, pattern: &str) -> bool {
-    self.to_lowercase().strip_non_alphanum() ==  pattern.to_lowercase().strip_non_alphanum()
+    // non-ASCII patterns are lowercased before stripping to mirror the sample string
+    let pattern = if pattern.is_ascii() { pattern.strip_non_alphanum() } else { pattern.to_lowercase().strip_non_alphanum() };
+    match_ci_alphanum_by_position(self, &pattern, BoundsPosition::Whole)
   }
 
   /// Starts with a case-insensitive sequence
   fn starts_with_ci(&self, pattern: &str) -> bool {
-    self.to_lowercase().starts_with(&pattern.to_lowercase())
+    match_ci_by_position(self, pattern, BoundsPosition::Starts)
   }
   
   /// Starts with a case-insensitive alphanumeric sequence
   fn starts_with_ci_alphanum(&self, pattern: &str) -> bool {
-    self.to_lowercase().strip_non_alphanum().starts_with(&pattern.to_lowercase())
+    match_ci_alphanum_by_position(self, pattern, BoundsPosition::Starts)
   }
   
   /// Ends with a case-insensitive sequence
   fn ends_with_ci(&self, pattern: &str) -> bool {
-    self.to_lowercase().ends_with(&pattern.to_lowercase())
+    match_ci_by_position(self, pattern, BoundsPosition::Ends)
   }
   
   /// Ends with a case-insensitive alphanumeric sequence
   fn ends_with_ci_alphanum(&self, pattern: &str) -> bool {
-    self.to_lowercase().strip_non_alphanum().ends_with(&pattern.to_lowercase())
+    match_ci_alphanum_by_position(self, pattern, BoundsPosition::Ends)
   }
 
   /// Contains a case-insensitive sequence
   fn contains_ci(&self, pattern: &str) -> bool {
-    self.to_lowercase().contains(&pattern.to_lowercase())
+    match_ci_by_position(self, pattern, BoundsPosition::Contains)
   }
   
   /// Contains a case-insensitive alphanumeric sequence
   fn contains_ci_alphanum(&self, pattern: &str) -> bool {
-    self.to_lowercase().strip_non_alphanum().contains(&pattern.to_lowercase())
+    match_ci_alphanum_by_position(self, pattern, BoundsPosition::Contains)
+  }
+
+  fn equals_normalized(&self, pattern: &str) -> bool {
+    match_normalized_by_position(self, pattern, BoundsPosition::Whole)
   }
 }
 
@@ -82,6 +102,15 @@ pub trait MatchOccurrences {
 
   /// Match occurrences of a single character
   fn find_char_indices(&self, pat: char) -> Vec<usize>;
+
+  /// Find which of several patterns occurs first returning its byte offset and index in the pattern list,
+  /// e.g. (1, 0) for "a=b;c" with ["=", ";"]. If patterns match at the same offset, the earlier pattern wins.
+  /// Empty patterns are ignored
+  fn first_match_of(&self, patterns: &[&str]) -> Option<(usize, usize)>;
+
+  /// Return the byte offset and the matched text in its original letter case for each non-overlapping
+  /// case-insensitive match, e.g. [(0, "Cat"), (4, "cAt")] for "Cat cAt" with "cat". Empty patterns match nothing
+  fn find_all_ci(&self, pat: &str) -> Vec<(usize, String)>;
 }
 
 
@@ -95,6 +124,26 @@ impl MatchOccurrences for str {
   fn find_char_indices(&self, pat: char) -> Vec<usize> {
     self.match_indices(pat).into_iter().map(|pair| pair.0).collect::<Vec<usize>>()
   }
+
+  fn first_match_of(&self, patterns: &[&str]) -> Option<(usize, usize)> {
+    patterns.iter().enumerate()
+      .filter(|(_, pattern)| !pattern.is_empty())
+      .filter_map(|(pattern_index, pattern)| self.find(pattern).map(|offset| (offset, pattern_index)))
+      .min()
+  }
+
+  fn find_all_ci(&self, pat: &str) -> Vec<(usize, String)> {
+    let mut matches: Vec<(usize, String)> = Vec::new();
+    if pat.is_empty() {
+      return matches;
+    }
+    let mut offset = 0;
+    while let Some(range) = find_ci(&self[offset..], pat) {
+      matches.push((offset + range.start, self[offset + range.start..offset + range.end].to_string()));
+      offset += range.end;
+    }
+    matches
+  }
 }
 
 
@@ -128,34 +177,27 @@ pub trait SimpleMatchesMany where Self:SimpleMatch {
 * Common function to match scalar StringBounds rules
 */
 pub(crate) fn match_bounds_rule(txt: &str, item: &StringBounds) -> bool {
-  let cm = item.case_mode();
-  let ci = item.case_insensitive();
-  // cast the sample string to lowercase for case-insenitive matches
-  let base = if ci {
-    match cm {
-      CaseMatchMode::AlphanumInsensitive => txt.to_lowercase().strip_non_alphanum(),
-      _ => txt.to_lowercase()
-    }
-  } else {
-    txt.to_owned()
-  };
-  // cast the simple pattern to lowercase for case-insenitive matches
-  let pattern = if ci {
-    item.pattern().to_lowercase()
-  } else {
-    item.pattern().to_owned()
-  };
-  // check if outcome of starts_with, ends_with or contains test matches the positivity value
-  let is_matched = if item.starts_with() {
-    base.starts_with(&pattern)
+  let position = if item.starts_with() {
+    BoundsPosition::Starts
   } else if item.ends_with() {
-    base.ends_with(&pattern)
+    BoundsPosition::Ends
   } else if item.matches_whole() {
-    base == pattern
+    BoundsPosition::Whole
+  } else if item.contains_word() {
+    BoundsPosition::Word
   } else {
-    base.contains(&pattern)
-  } == item.is_positive();
-  is_matched
+    BoundsPosition::Contains
+  };
+  let pattern = item.pattern();
+  // check if outcome of starts_with, ends_with, whole or contains test matches the positivity value
+  let is_matched = match item.case_mode() {
+    CaseMatchMode::Sensitive => match_cs_by_position(txt, pattern, position),
+    CaseMatchMode::Insensitive => match_ci_by_position(txt, pattern, position),
+    CaseMatchMode::AlphanumInsensitive => match_ci_alphanum_by_position(txt, pattern, position),
+    CaseMatchMode::Normalized => match_normalized_by_position(txt, pattern, position),
+    CaseMatchMode::AsciiInsensitive => match_ascii_ci_by_position(txt, pattern, position),
+  };
+  is_matched == item.is_positive()
 }
 
 /*
@@ -165,10 +207,31 @@ pub(crate) fn match_bounds_rule_set(txt: &str, item: &StringBounds) -> bool {
   match item {
     StringBounds::And(inner_rules) => txt.matched_conditional(&inner_rules).into_iter().all(|result| result),
     StringBounds::Or(inner_rules) => txt.matched_conditional(&inner_rules).into_iter().any(|result| result),
+    StringBounds::HasType(char_type, is_positive) => txt.contains_type(char_type.clone()) == *is_positive,
+    // an invalid regular expression fails the rule whether positive or negative
+    #[cfg(feature = "regex")]
+    StringBounds::Regex(pattern, is_positive) => regex::Regex::new(pattern).is_ok_and(|re| re.is_match(txt) == *is_positive),
     _ => match_bounds_rule(txt, item)
   }
 }
 
+/*
+* Check rules that compile patterns, including those in nested rule sets. Scalar rules are always valid
+*/
+fn validate_rule_sets(rules: &[StringBounds]) -> Result<(), MatchError> {
+  for item in rules {
+    match item {
+      StringBounds::And(inner_rules) | StringBounds::Or(inner_rules) => validate_rule_sets(inner_rules)?,
+      #[cfg(feature = "regex")]
+      StringBounds::Regex(pattern, _) if regex::Regex::new(pattern).is_err() => {
+        return Err(MatchError::InvalidPattern(pattern.to_string()));
+      },
+      _ => ()
+    }
+  }
+  Ok(())
+}
+
 impl SimpleMatchesMany for str {
 
   // test for multiple conditions. All other trait methods are derived from this
@@ -181,12 +244,39 @@ impl SimpleMatchesMany for str {
    }
 }
 
+/// Score strings by the summed weights of matched rules for ranking rather than boolean filtering
+pub trait SimpleMatchScore where Self:SimpleMatchesMany {
+
+  /// Return the sum of the weights of all matched rules
+  /// e.g. (contains "urgent", 5.0) and (contains "fyi", -2.0)
+  fn score_conditional(&self, weighted_rules: &[(StringBounds, f64)]) -> f64;
+
+}
+
+impl SimpleMatchScore for str {
+
+  fn score_conditional(&self, weighted_rules: &[(StringBounds, f64)]) -> f64 {
+    weighted_rules.iter()
+      .filter(|(rule, _)| match_bounds_rule_set(self, rule))
+      .map(|(_, weight)| weight)
+      .sum()
+  }
+
+}
+
 /// Test multiple patterns and return boolean
 pub trait SimpleMatchAll where Self:SimpleMatchesMany {
 
   /// test for multiple conditions. All other trait methods are derived from this
   fn match_all_conditional(&self, pattern_sets: &[StringBounds]) -> bool;
 
+  /// Variant of match_all_conditional() that validates the rules first,
+  /// returning an error with the offending pattern if a Regex rule is invalid rather than silently failing to match
+  fn try_match_all_conditional(&self, pattern_sets: &[StringBounds]) -> Result<bool, MatchError> {
+    validate_rule_sets(pattern_sets)?;
+    Ok(self.match_all_conditional(pattern_sets))
+  }
+
   /// test for multiple conditions with simple tuple pairs of pattern + case-insenitive flag
   fn contains_all_conditional(&self, pattern_sets: &[(&str, bool)]) -> bool {
     let pattern_sets: Vec<StringBounds> = pairs_to_string_bounds(pattern_sets, BoundsPosition::Contains);
@@ -291,6 +381,19 @@ pub trait SimplContainsType where Self:SimpleMatch {
 
   /// ends with one or more characters in the specified sets
   fn ends_with_types(&self, char_types: &[CharType]) -> bool;
+
+  /// starts with a run of at least min characters in the specified set, e.g. 4 digits
+  fn starts_with_count_type(&self, char_type: CharType, min: usize) -> bool;
+
+  /// ends with a run of at least min characters in the specified set
+  fn ends_with_count_type(&self, char_type: CharType, min: usize) -> bool;
+
+  /// every character is in the specified set. An empty string is vacuously true
+  fn only_contains_type(&self, char_type: CharType) -> bool;
+
+  /// every character is in at least one of the specified sets, e.g. only letters and spaces with [CharType::Alpha, CharType::Spaces]
+  /// An empty string is vacuously true
+  fn only_contains_types(&self, char_types: &[CharType]) -> bool;
   
 }
 
@@ -341,6 +444,24 @@ impl SimplContainsType for str {
       false
     }
    }
+
+   /// starts with a run of at least min characters in the specified set
+   fn starts_with_count_type(&self, char_type: CharType, min: usize) -> bool {
+    self.chars().take(min).filter(|ch| char_type.is_in_range(ch)).count() == min
+   }
+
+   /// ends with a run of at least min characters in the specified set
+   fn ends_with_count_type(&self, char_type: CharType, min: usize) -> bool {
+    self.chars().rev().take(min).filter(|ch| char_type.is_in_range(ch)).count() == min
+   }
+
+   fn only_contains_type(&self, char_type: CharType) -> bool {
+    self.chars().all(|ch| char_type.is_in_range(&ch))
+   }
+
+   fn only_contains_types(&self, char_types: &[CharType]) -> bool {
+    self.chars().all(|ch| char_types.iter().any(|ct| ct.is_in_range(&ch)))
+   }
    
 
 }
@@ -355,6 +476,13 @@ pub trait SimpleFilterAll<'a, T> {
   fn filter_all_rules(&'a self, rules: &BoundsBuilder) -> Vec<T> {
     self.filter_all_conditional(&rules.as_vec())
   }
+
+  /// Count the strings matching all of the rules without collecting them
+  fn count_all_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize;
+
+  fn count_all_rules(&'a self, rules: &BoundsBuilder) -> usize {
+    self.count_all_conditional(&rules.as_vec())
+  }
   
 }
 
@@ -366,6 +494,10 @@ impl<'a> SimpleFilterAll<'a, &'a str> for [&str] {
     self.into_iter().map(|s| s.to_owned()).filter(|s| s.match_all_conditional(pattern_sets)).collect::<Vec<&'a str>>()
   }
 
+  fn count_all_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize {
+    self.iter().filter(|s| s.match_all_conditional(pattern_sets)).count()
+  }
+
 }
 
 /// Variant implementation for owned strings
@@ -375,6 +507,10 @@ impl<'a> SimpleFilterAll<'a, String> for [String] {
     self.into_iter().filter(|s| s.match_all_conditional(pattern_sets)).map(|s| s.to_owned()).collect::<Vec<String>>()
   }
 
+  fn count_all_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize {
+    self.iter().filter(|s| s.match_all_conditional(pattern_sets)).count()
+  }
+
 }
 
 /// Test multiple patterns and return a filtered vector of string slices by any of the pattern rules
@@ -386,6 +522,13 @@ pub trait SimpleFilterAny<'a, T> {
   fn filter_any_rules(&'a self, rules: &BoundsBuilder) -> Vec<T> {
     self.filter_any_conditional(&rules.as_vec())
   }
+
+  /// Count the strings matching any of the rules without collecting them
+  fn count_any_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize;
+
+  fn count_any_rules(&'a self, rules: &BoundsBuilder) -> usize {
+    self.count_any_conditional(&rules.as_vec())
+  }
   
 }
 
@@ -397,6 +540,10 @@ impl<'a> SimpleFilterAny<'a, &'a str> for [&str] {
     self.into_iter().map(|s| s.to_owned()).filter(|s| s.match_any_conditional(pattern_sets)).collect::<Vec<&'a str>>()
   }
 
+  fn count_any_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize {
+    self.iter().filter(|s| s.match_any_conditional(pattern_sets)).count()
+  }
+
 }
 
 /// Variant implementation for owned strings
@@ -406,4 +553,34 @@ impl<'a> SimpleFilterAny<'a, String> for [String] {
     self.into_iter().filter(|s| s.match_any_conditional(pattern_sets)).map(|s| s.to_owned()).collect::<Vec<String>>()
   }
 
+  fn count_any_conditional(&'a self, pattern_sets: &[StringBounds]) -> usize {
+    self.iter().filter(|s| s.match_any_conditional(pattern_sets)).count()
+  }
+
+}
+
+/// Filter a vector of strings by the summed weights of matched rules
+pub trait SimpleFilterScore<'a, T> {
+
+  /// Keep only items whose score via score_conditional() exceeds the threshold
+  fn filter_by_score(&'a self, weighted_rules: &[(StringBounds, f64)], threshold: f64) -> Vec<T>;
+
+}
+
+/// Filter string slices by their scores
+impl<'a> SimpleFilterScore<'a, &'a str> for [&str] {
+
+  fn filter_by_score(&'a self, weighted_rules: &[(StringBounds, f64)], threshold: f64) -> Vec<&'a str> {
+    self.iter().copied().filter(|s| s.score_conditional(weighted_rules) > threshold).collect()
+  }
+
+}
+
+/// Variant implementation for owned strings
+impl<'a> SimpleFilterScore<'a, String> for [String] {
+
+  fn filter_by_score(&'a self, weighted_rules: &[(StringBounds, f64)], threshold: f64) -> Vec<String> {
+    self.iter().filter(|s| s.score_conditional(weighted_rules) > threshold).cloned().collect()
+  }
+
 }
@@ -18,3 +18,16 @@ impl<T: ToString> ToStrings for [T] {
       self.into_iter().map(|s| s.to_string()).collect::<Vec<String>>()
   }
 }
+
+/// Join arrays or vectors of strings with extra formatting
+pub trait JoinExt {
+  /// Prefix each element before joining with the separator,
+  /// e.g. ["a", "b"] with "- " and "\n" yields a Markdown list "- a\n- b"
+  fn join_prefixed(&self, prefix: &str, separator: &str) -> String;
+}
+
+impl<T: AsRef<str>> JoinExt for [T] {
+  fn join_prefixed(&self, prefix: &str, separator: &str) -> String {
+    self.iter().map(|item| [prefix, item.as_ref()].concat()).collect::<Vec<String>>().join(separator)
+  }
+}
@@ -0,0 +1,224 @@
+use std::ops::Range;
+use crate::{utils::to_sentence_spans, BoundsBuilder, CharType, SimpleMatchAll};
+
+/// Characters other than letters and digits allowed in the local part of an email-like token
+const EMAIL_LOCAL_CHARS: [char; 5] = ['.', '_', '%', '+', '-'];
+
+/// Characters that may surround an email address in running text, e.g. <a@b.com> or (a@b.com),
+/// and are trimmed before validation
+const EMAIL_WRAPPER_CHARS: [char; 12] = ['<', '>', '(', ')', '[', ']', '"', '\'', ',', ';', ':', '.'];
+
+/// Methods to detect and extract tokens with a recognisable internal structure from longer strings
+pub trait ToTokens {
+
+  /// Check if the whole string looks like an email address.
+  /// This is a lightweight heuristic rather than full RFC 5322 validation:
+  /// there must be exactly one @ with a non-empty local part made of letters, digits or . _ % + -
+  /// and a domain with at least two dot-separated labels of letters, digits or hyphens
+  fn is_email_like(&self) -> bool;
+
+  /// Extract all email-like tokens from a longer text
+  /// Tokens are split on whitespace and stripped of surrounding brackets, quotes and trailing punctuation
+  /// before being validated with is_email_like()
+  fn extract_emails(&self) -> Vec<String>;
+
+  /// Split a command-line-like string into arguments on unquoted whitespace.
+  /// Single- or double-quoted runs are kept together and the surrounding quotes removed.
+  /// Outside quotes a backslash escapes the next character, e.g. a quote or space.
+  /// Inside double quotes a backslash only escapes a double quote or another backslash,
+  /// while single-quoted runs are taken literally as in POSIX shells.
+  /// An unterminated quote is treated as extending to the end of the string
+  fn shell_split(&self) -> Vec<String>;
+
+  /// Split a string into words on whitespace, trimming leading and trailing characters
+  /// that are not letters or digits, e.g. brackets, quotes or punctuation. Inner punctuation such as
+  /// apostrophes and hyphens is kept. Tokens without any letters or digits are skipped
+  fn to_words(&self) -> Vec<String>;
+
+  /// Words as extracted by to_words() paired with their byte ranges in the original string,
+  /// e.g. [("the", 0..3), ("cat", 4..7)] for "the cat"
+  fn word_positions(&self) -> Vec<(String, Range<usize>)>;
+
+  /// Split text into trimmed sentences ending with ., ! or ? or an ellipsis followed by whitespace or the end of the text.
+  /// Closing quotes or brackets after the terminal punctuation belong to the sentence.
+  /// This is a heuristic: abbreviations such as "e.g." followed by a space also end a sentence
+  fn to_sentences(&self) -> Vec<String>;
+
+  /// Split a camelCase or PascalCase identifier into words, e.g. ["get", "HTTP", "Response", "Code"] from "getHTTPResponseCode".
+  /// A new word starts at an uppercase letter after a lowercase letter or digit, or at the last letter of an uppercase run
+  /// followed by a lowercase letter. Digits stay with the preceding word, so "parseURL2Json" yields ["parse", "URL2", "Json"].
+  /// Other characters such as underscores, hyphens or spaces separate words and are dropped
+  fn split_camel_case(&self) -> Vec<String>;
+
+  /// Count the words, as extracted by to_words(), that match all rules in the set
+  fn count_words_matching(&self, rules: &BoundsBuilder) -> usize {
+    let rule_set = rules.as_vec();
+    self.to_words().iter().filter(|word| word.match_all_conditional(&rule_set)).count()
+  }
+
+  /// Build an acronym from the uppercased first character of each word
+  /// e.g. "North Atlantic Treaty Organization" yields "NATO"
+  fn abbreviate(&self) -> String {
+    self.abbreviate_with(0)
+  }
+
+  /// Build an acronym skipping words with fewer characters than min_word_len
+  /// e.g. "Department of Defense" with a minimum word length of 3 yields "DD"
+  fn abbreviate_with(&self, min_word_len: usize) -> String {
+    self.to_words().iter()
+      .filter(|word| word.chars().count() >= min_word_len)
+      .filter_map(|word| word.chars().next())
+      .flat_map(char::to_uppercase)
+      .collect()
+  }
+
+}
+
+impl ToTokens for str {
+
+  fn is_email_like(&self) -> bool {
+    if let Some((local, domain)) = self.split_once('@') {
+      is_valid_email_local_part(local) && is_valid_email_domain(domain)
+    } else {
+      false
+    }
+  }
+
+  fn extract_emails(&self) -> Vec<String> {
+    self.split_whitespace()
+      .map(|token| token.trim_matches(&EMAIL_WRAPPER_CHARS[..]))
+      .filter(|token| token.is_email_like())
+      .map(|token| token.to_string())
+      .collect()
+  }
+
+  fn to_words(&self) -> Vec<String> {
+    self.word_positions().into_iter().map(|(word, _)| word).collect()
+  }
+
+  fn word_positions(&self) -> Vec<(String, Range<usize>)> {
+    let mut words: Vec<(String, Range<usize>)> = Vec::new();
+    let mut token_start: Option<usize> = None;
+    for (index, c) in self.char_indices().chain([(self.len(), ' ')]) {
+      if !c.is_whitespace() {
+        token_start.get_or_insert(index);
+      } else if let Some(start) = token_start.take() {
+        // trim characters that are not letters or digits from both ends of the token
+        let token = &self[start..index];
+        let word_end = token.trim_end_matches(|c: char| !c.is_alphanumeric()).len();
+        let word_start = word_end - token[..word_end].trim_start_matches(|c: char| !c.is_alphanumeric()).len();
+        if word_start < word_end {
+          words.push((token[word_start..word_end].to_string(), start + word_start..start + word_end));
+        }
+      }
+    }
+    words
+  }
+
+  fn to_sentences(&self) -> Vec<String> {
+    to_sentence_spans(self).into_iter()
+      .map(|span| span.trim())
+      .filter(|sentence| !sentence.is_empty())
+      .map(|sentence| sentence.to_string())
+      .collect()
+  }
+
+  fn split_camel_case(&self) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut prev: Option<char> = None;
+    let mut chars = self.chars().peekable();
+    while let Some(c) = chars.next() {
+      if !c.is_alphanumeric() {
+        if !current.is_empty() {
+          words.push(std::mem::take(&mut current));
+        }
+        prev = None;
+        continue;
+      }
+      if c.is_uppercase() {
+        let next_is_lower = chars.peek().is_some_and(|next| next.is_lowercase());
+        let is_boundary = prev.is_some_and(|p| p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_is_lower));
+        if is_boundary {
+          words.push(std::mem::take(&mut current));
+        }
+      }
+      current.push(c);
+      prev = Some(c);
+    }
+    if !current.is_empty() {
+      words.push(current);
+    }
+    words
+  }
+
+  fn shell_split(&self) -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+    let mut current = String::new();
+    // tracks if the current argument has started, as quoted empty strings are valid arguments
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = self.chars().peekable();
+    while let Some(c) = chars.next() {
+      match quote {
+        Some('\'') => {
+          if c == '\'' {
+            quote = None;
+          } else {
+            current.push(c);
+          }
+        },
+        Some(q) => {
+          if c == q {
+            quote = None;
+          } else if c == '\\' && matches!(chars.peek(), Some(&next) if next == q || next == '\\') {
+            current.extend(chars.next());
+          } else {
+            current.push(c);
+          }
+        },
+        None => {
+          if c.is_whitespace() {
+            if in_arg {
+              args.push(std::mem::take(&mut current));
+              in_arg = false;
+            }
+          } else {
+            in_arg = true;
+            match c {
+              '"' | '\'' => quote = Some(c),
+              '\\' => current.push(chars.next().unwrap_or(c)),
+              _ => current.push(c),
+            }
+          }
+        }
+      }
+    }
+    if in_arg {
+      args.push(current);
+    }
+    args
+  }
+
+}
+
+/// The local part may not be empty, start or end with a dot or contain a second @
+fn is_valid_email_local_part(local: &str) -> bool {
+  let allowed = [CharType::AlphaNum, CharType::Chars(&EMAIL_LOCAL_CHARS)];
+  !local.is_empty()
+    && !local.starts_with('.')
+    && !local.ends_with('.')
+    && local.chars().all(|c| allowed.iter().any(|ct| ct.is_in_range(&c)))
+}
+
+/// The domain must have at least two non-empty labels, none of which may start or end with a hyphen
+fn is_valid_email_domain(domain: &str) -> bool {
+  let allowed = [CharType::AlphaNum, CharType::Char('-')];
+  let labels: Vec<&str> = domain.split('.').collect();
+  labels.len() > 1 && labels.iter().all(|label| {
+    !label.is_empty()
+      && !label.starts_with('-')
+      && !label.ends_with('-')
+      && label.chars().all(|c| allowed.iter().any(|ct| ct.is_in_range(&c)))
+  })
+}
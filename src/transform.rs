@@ -0,0 +1,181 @@
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+use crate::utils::{capitalize_first_alphabetic, find_ci, matched_prefix_len, to_sentence_spans};
+
+/// Methods to rearrange the characters of a string
+pub trait SimpleTransform {
+
+  /// Reverse the order of Unicode scalar values (chars).
+  /// Combining marks such as the diaeresis in a decomposed "noël" will be attached to the preceding letter
+  /// in the reversed string. Use reverse_graphemes() with the unicode feature to avoid this
+  fn reverse_chars(&self) -> String;
+
+  /// Left-pad each run of ASCII digits with zeros to the specified width for sortable keys,
+  /// e.g. "file2" becomes "file0002" with a width of 4. Longer digit runs are unchanged.
+  /// Each digit run is padded separately, so decimal or thousand separators split numbers
+  fn pad_numbers(&self, width: usize) -> String;
+
+  /// Hard-wrap text on whitespace so that no line exceeds the width in characters.
+  /// Words longer than the width are broken. Existing newlines are kept as paragraph boundaries,
+  /// while other runs of whitespace collapse to single spaces. A width of 0 leaves the text unchanged
+  fn wrap_lines(&self, width: usize) -> String;
+
+  /// Lowercase the whole string and capitalize the first letter of each sentence as split by to_sentences(),
+  /// e.g. "HELLO. HOW ARE YOU?" becomes "Hello. How are you?". Whitespace between sentences is preserved.
+  /// Acronyms and proper nouns are lowercased too, as they cannot be told apart from shouted words
+  fn to_sentence_case(&self) -> String;
+
+  /// Uppercase the first letter of each sentence leaving all other characters unchanged,
+  /// e.g. "hello. the iPhone is NEW." becomes "Hello. The iPhone is NEW.".
+  /// Sentence boundaries are detected as in to_sentences(), so a sentence starting with "iPhone" becomes "IPhone"
+  fn capitalize_sentences(&self) -> String;
+
+  /// Replace all keys with their values in one left-to-right pass, e.g. [("USA", "United States"), ("UK", "United Kingdom")].
+  /// The longest matching key wins at each position and replacement text is not scanned again,
+  /// so values containing other keys are not expanded recursively. Empty keys are ignored
+  fn replace_map(&self, pairs: &[(&str, &str)]) -> String;
+
+  /// Variant of replace_map() matching keys case-insensitively
+  fn replace_map_ci(&self, pairs: &[(&str, &str)]) -> String;
+
+  /// Repeat the string until it reaches the target length in characters, truncating the last repetition,
+  /// e.g. "ababa" from "ab" with a target of 5. An empty string yields an empty string
+  fn repeat_to_length(&self, target: usize) -> String;
+
+  /// Remove all occurrences of the pattern, e.g. zero-width spaces or a marker token. An empty pattern leaves the string unchanged
+  fn remove_all(&self, pattern: &str) -> String;
+
+  /// Variant of remove_all() matching the pattern case-insensitively, e.g. "Test" and "TEST" are removed with "test"
+  fn remove_all_ci(&self, pattern: &str) -> String;
+
+  /// Reverse the order of extended grapheme clusters, keeping combining marks with their base characters
+  #[cfg(feature = "unicode")]
+  fn reverse_graphemes(&self) -> String;
+
+}
+
+impl SimpleTransform for str {
+
+  fn reverse_chars(&self) -> String {
+    self.chars().rev().collect()
+  }
+
+  fn pad_numbers(&self, width: usize) -> String {
+    let mut output = String::with_capacity(self.len());
+    let mut rest = self;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+      output.push_str(&rest[..start]);
+      let digits = &rest[start..];
+      let num_len = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+      output.push_str(&format!("{:0>width$}", &digits[..num_len]));
+      rest = &digits[num_len..];
+    }
+    output.push_str(rest);
+    output
+  }
+
+  fn wrap_lines(&self, width: usize) -> String {
+    if width < 1 {
+      return self.to_string();
+    }
+    let mut lines: Vec<String> = Vec::new();
+    for paragraph in self.lines() {
+      let mut line = String::new();
+      let mut line_len = 0;
+      for word in paragraph.split_whitespace() {
+        let word_len = word.chars().count();
+        if line_len > 0 && line_len + 1 + word_len <= width {
+          line.push(' ');
+          line.push_str(word);
+          line_len += 1 + word_len;
+          continue;
+        }
+        if line_len > 0 {
+          lines.push(std::mem::take(&mut line));
+        }
+        // break words longer than the width into chunks, the last of which starts the next line
+        let chars: Vec<char> = word.chars().collect();
+        let mut chunks = chars.chunks(width).peekable();
+        while let Some(chunk) = chunks.next() {
+          if chunks.peek().is_some() {
+            lines.push(chunk.iter().collect());
+          } else {
+            line = chunk.iter().collect();
+            line_len = chunk.len();
+          }
+        }
+      }
+      lines.push(line);
+    }
+    lines.join("\n")
+  }
+
+  fn to_sentence_case(&self) -> String {
+    let lower = self.to_lowercase();
+    to_sentence_spans(&lower).into_iter().map(capitalize_first_alphabetic).collect()
+  }
+
+  fn capitalize_sentences(&self) -> String {
+    to_sentence_spans(self).into_iter().map(capitalize_first_alphabetic).collect()
+  }
+
+  fn replace_map(&self, pairs: &[(&str, &str)]) -> String {
+    replace_map_by_case(self, pairs, false)
+  }
+
+  fn replace_map_ci(&self, pairs: &[(&str, &str)]) -> String {
+    replace_map_by_case(self, pairs, true)
+  }
+
+  fn repeat_to_length(&self, target: usize) -> String {
+    self.chars().cycle().take(target).collect()
+  }
+
+  fn remove_all(&self, pattern: &str) -> String {
+    self.replace(pattern, "")
+  }
+
+  fn remove_all_ci(&self, pattern: &str) -> String {
+    if pattern.is_empty() {
+      return self.to_string();
+    }
+    let mut output = String::with_capacity(self.len());
+    let mut remainder = self;
+    while let Some(range) = find_ci(remainder, pattern) {
+      output.push_str(&remainder[..range.start]);
+      remainder = &remainder[range.end..];
+    }
+    output.push_str(remainder);
+    output
+  }
+
+  #[cfg(feature = "unicode")]
+  fn reverse_graphemes(&self) -> String {
+    self.graphemes(true).rev().collect()
+  }
+
+}
+
+/// Scan the string once replacing the longest matching key at each character position
+fn replace_map_by_case(txt: &str, pairs: &[(&str, &str)], case_insensitive: bool) -> String {
+  let mut output = String::with_capacity(txt.len());
+  let mut index = 0;
+  while index < txt.len() {
+    let rest = &txt[index..];
+    let longest_match = pairs.iter()
+      .filter(|(key, _)| !key.is_empty())
+      .filter_map(|(key, value)| matched_prefix_len(rest, key, case_insensitive).map(|len| (len, *value)))
+      .fold(None, |longest: Option<(usize, &str)>, (len, value)| match longest {
+        Some((longest_len, _)) if longest_len >= len => longest,
+        _ => Some((len, value)),
+      });
+    if let Some((len, value)) = longest_match {
+      output.push_str(value);
+      index += len;
+    } else if let Some(c) = rest.chars().next() {
+      output.push(c);
+      index += c.len_utf8();
+    }
+  }
+  output
+}
@@ -1,5 +1,6 @@
 
-use crate::{enums::StringBounds, BoundsPosition, CaseMatchMode};
+use std::ops::Range;
+use crate::{diacritics::fold_for_match, enums::StringBounds, BoundsPosition, CaseMatchMode, StripCharacters};
 
 /// Miscellaneous utility functions that do not belong to structs
 /// corrects a numeric string after it has been extracted by removing trailing dots or commas
@@ -29,6 +30,81 @@ pub(crate) fn pairs_to_string_bounds<'a>(pairs: &'a [(&str, bool)], mode: Bounds
   pairs.into_iter().map(|(txt, ci)| StringBounds::new(mode, *txt, true, CaseMatchMode::insensitive(*ci))).collect()
 }
 
+/// Case-sensitive match by position
+pub(crate) fn match_cs_by_position(txt: &str, pattern: &str, position: BoundsPosition) -> bool {
+  match position {
+    BoundsPosition::Starts => txt.starts_with(pattern),
+    BoundsPosition::Ends => txt.ends_with(pattern),
+    BoundsPosition::Whole => txt == pattern,
+    BoundsPosition::Contains => txt.contains(pattern),
+    BoundsPosition::Word => contains_word(txt, pattern),
+  }
+}
+
+/// Case- and accent-insensitive match by position, e.g. "Résumé" matches "resume" as a whole string
+pub(crate) fn match_normalized_by_position(txt: &str, pattern: &str, position: BoundsPosition) -> bool {
+  match_cs_by_position(&fold_for_match(txt), &fold_for_match(pattern), position)
+}
+
+/// Case-insensitive match by position with a fast path that avoids allocation and Unicode case tables
+/// if both the sample string and pattern are ASCII. Otherwise both are cast to lower case for comparison
+pub(crate) fn match_ci_by_position(txt: &str, pattern: &str, position: BoundsPosition) -> bool {
+  if txt.is_ascii() && pattern.is_ascii() {
+    match_ascii_ci_by_position(txt, pattern, position)
+  } else {
+    let base = txt.to_lowercase();
+    let pat = pattern.to_lowercase();
+    match position {
+      BoundsPosition::Starts => base.starts_with(&pat),
+      BoundsPosition::Ends => base.ends_with(&pat),
+      BoundsPosition::Whole => base == pat,
+      BoundsPosition::Contains => base.contains(&pat),
+      BoundsPosition::Word => contains_word(&base, &pat),
+    }
+  }
+}
+
+/// Match by position folding only ASCII letters without allocation, e.g. "ABC" matches "abc", but "É" does not match "é".
+/// Non-ASCII characters are compared byte by byte, which is safe as UTF-8 sequences cannot match mid-character
+pub(crate) fn match_ascii_ci_by_position(txt: &str, pattern: &str, position: BoundsPosition) -> bool {
+  let (base, pat) = (txt.as_bytes(), pattern.as_bytes());
+  match position {
+    BoundsPosition::Starts => base.len() >= pat.len() && base[..pat.len()].eq_ignore_ascii_case(pat),
+    BoundsPosition::Ends => base.len() >= pat.len() && base[base.len() - pat.len()..].eq_ignore_ascii_case(pat),
+    BoundsPosition::Whole => base.eq_ignore_ascii_case(pat),
+    BoundsPosition::Contains => pat.is_empty() || base.windows(pat.len()).any(|window| window.eq_ignore_ascii_case(pat)),
+    BoundsPosition::Word => !pat.is_empty() && base.len() >= pat.len() && (0..=base.len() - pat.len()).any(|index| {
+      base[index..index + pat.len()].eq_ignore_ascii_case(pat)
+        && !txt[..index].chars().next_back().is_some_and(char::is_alphanumeric)
+        && !txt[index + pat.len()..].chars().next().is_some_and(char::is_alphanumeric)
+    }),
+  }
+}
+
+/// Case-sensitive check if the pattern occurs as a whole word, i.e. not immediately preceded or followed by a letter or digit,
+/// e.g. "cat" matches "the cat sat" and "cat-flap", but not "category"
+pub(crate) fn contains_word(txt: &str, pattern: &str) -> bool {
+  !pattern.is_empty() && txt.match_indices(pattern).any(|(index, matched)| {
+    let before = txt[..index].chars().next_back();
+    let after = txt[index + matched.len()..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+  })
+}
+
+/// Case-insensitive match by position on the alphanumeric characters of the sample string only
+pub(crate) fn match_ci_alphanum_by_position(txt: &str, pattern: &str, position: BoundsPosition) -> bool {
+  // stripping non-alphanumeric characters would remove word boundaries
+  if let BoundsPosition::Word = position {
+    return match_ci_by_position(txt, pattern, position);
+  }
+  if txt.is_ascii() && pattern.is_ascii() {
+    match_ci_by_position(&txt.strip_non_alphanum(), pattern, position)
+  } else {
+    // lowercase before stripping, as lowercasing may add combining marks, e.g. İ becomes i + U+0307
+    match_cs_by_position(&txt.to_lowercase().strip_non_alphanum(), &pattern.to_lowercase(), position)
+  }
+}
+
 /// Extract a string segment by its index where a negative value starts from the end
 /// and an unmatched element returns None
 pub(crate) fn extract_string_element_by_index(parts: Vec<String>, index: i32) -> Option<String> {
@@ -40,3 +116,130 @@ pub(crate) fn extract_string_element_by_index(parts: Vec<String>, index: i32) ->
     None
   }
 }
+
+/// Rewrite numbers wholly enclosed in parentheses with a leading minus, e.g. "(1,234.56)" becomes "-1,234.56 "
+/// Only applies if the parentheses tightly bound a sequence of digits with optional dots or commas
+pub(crate) fn accounting_negatives_to_signed(txt: &str) -> String {
+  let mut output = String::with_capacity(txt.len());
+  let mut remainder = txt;
+  while let Some(start) = remainder.find('(') {
+    output.push_str(&remainder[..start]);
+    let after_open = &remainder[start + 1..];
+    let inner_len = after_open.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).unwrap_or(after_open.len());
+    let inner = &after_open[..inner_len];
+    if inner.starts_with(|c: char| c.is_ascii_digit()) && after_open[inner_len..].starts_with(')') {
+      output.push('-');
+      output.push_str(inner);
+      // replace the closing parenthesis with a space to keep the number boundary
+      output.push(' ');
+      remainder = &after_open[inner_len + 1..];
+    } else {
+      output.push('(');
+      remainder = after_open;
+    }
+  }
+  output.push_str(remainder);
+  output
+}
+
+/// Rewrite numbers followed by a trailing minus with a leading minus, e.g. "5.00-" becomes "-5.00 "
+/// The trailing minus must not be followed by a letter or digit, so ranges such as "5-10" and compounds such as "5-year" are unchanged.
+/// Numbers that already have a leading minus are left as they are
+pub(crate) fn trailing_minus_to_signed(txt: &str) -> String {
+  let mut output = String::with_capacity(txt.len() + 1);
+  let mut remainder = txt;
+  while let Some(start) = remainder.find(|c: char| c.is_ascii_digit()) {
+    output.push_str(&remainder[..start]);
+    let number_start = &remainder[start..];
+    let num_len = number_start.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',')).unwrap_or(number_start.len());
+    let (num_str, rest) = number_start.split_at(num_len);
+    let has_trailing_minus = rest.strip_prefix('-').is_some_and(|after| !after.starts_with(char::is_alphanumeric));
+    if has_trailing_minus && !output.ends_with('-') {
+      output.push('-');
+      output.push_str(num_str);
+      // replace the trailing minus with a space to keep the number boundary
+      output.push(' ');
+      remainder = &rest[1..];
+    } else {
+      output.push_str(num_str);
+      remainder = rest;
+    }
+  }
+  output.push_str(remainder);
+  output
+}
+
+/// Uppercase the first character of a string leaving the remainder unchanged
+pub(crate) fn capitalize_first(txt: &str) -> String {
+  let mut chars = txt.chars();
+  if let Some(first) = chars.next() {
+    first.to_uppercase().chain(chars).collect()
+  } else {
+    String::new()
+  }
+}
+
+/// Uppercase the first alphabetic character of a string leaving all other characters unchanged,
+/// e.g. "(hello" becomes "(Hello"
+pub(crate) fn capitalize_first_alphabetic(txt: &str) -> String {
+  if let Some(index) = txt.find(char::is_alphabetic) {
+    [&txt[..index], &capitalize_first(&txt[index..])].concat()
+  } else {
+    txt.to_string()
+  }
+}
+
+/// Split text into sentence spans that include their terminal punctuation and any following whitespace,
+/// so that joining the spans reproduces the original text. A sentence ends with a run of ., ! or ? or an ellipsis,
+/// optionally followed by closing quotes or brackets, before whitespace or the end of the text.
+/// Abbreviations such as "e.g. this" are not detected and will end a sentence
+pub(crate) fn to_sentence_spans(txt: &str) -> Vec<&str> {
+  let mut spans: Vec<&str> = Vec::new();
+  let mut start = 0;
+  let mut chars = txt.char_indices().peekable();
+  while let Some((_, c)) = chars.next() {
+    if !matches!(c, '.' | '!' | '?' | '…') {
+      continue;
+    }
+    while chars.next_if(|&(_, next)| matches!(next, '.' | '!' | '?' | '…' | '"' | '\'' | ')' | ']' | '”' | '’' | '»')).is_some() {}
+    match chars.peek() {
+      Some(&(_, next)) if next.is_whitespace() => {
+        while chars.next_if(|&(_, next)| next.is_whitespace()).is_some() {}
+        let end = chars.peek().map_or(txt.len(), |&(index, _)| index);
+        spans.push(&txt[start..end]);
+        start = end;
+      },
+      _ => ()
+    }
+  }
+  if start < txt.len() {
+    spans.push(&txt[start..]);
+  }
+  spans
+}
+
+/// Byte length of the start of the text matching the key character by character, if it matches
+pub(crate) fn matched_prefix_len(txt: &str, key: &str, case_insensitive: bool) -> Option<usize> {
+  if !case_insensitive {
+    return txt.starts_with(key).then_some(key.len());
+  }
+  let mut len = 0;
+  let mut chars = txt.chars();
+  for key_char in key.chars() {
+    let c = chars.next()?;
+    if c != key_char && !c.to_lowercase().eq(key_char.to_lowercase()) {
+      return None;
+    }
+    len += c.len_utf8();
+  }
+  Some(len)
+}
+
+/// Find the byte range of the first case-insensitive match of the pattern, comparing character by character
+/// so that the range always refers to the original string, even if lowercasing changes byte lengths
+pub(crate) fn find_ci(txt: &str, pattern: &str) -> Option<Range<usize>> {
+  txt.char_indices()
+    .map(|(index, _)| index)
+    .chain([txt.len()])
+    .find_map(|index| matched_prefix_len(&txt[index..], pattern, true).map(|len| index..index + len))
+}
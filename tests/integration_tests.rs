@@ -649,4 +649,1192 @@ fn test_and_or_inner_bounds_builder() {
   assert_eq!(filtered_lines_3, expected_lines_3);
 
 
-}
\ No newline at end of file
+}
+#[test]
+fn test_extract_emails() {
+  let sample_str = "Please contact a.b@example.com or (support@mail.example.org), but not a@@b or info@localhost.";
+  let expected_emails = ["a.b@example.com", "support@mail.example.org"].to_strings();
+  assert_eq!(sample_str.extract_emails(), expected_emails);
+
+  assert!("a.b@example.com".is_email_like());
+  assert!(!"a@@b".is_email_like());
+  assert!(!"@example.com".is_email_like());
+  assert!(!"name@example".is_email_like());
+}
+
+#[test]
+fn test_shell_split() {
+  let command = r#"echo "hello world" 'it'\''s ok'"#;
+  let expected_args = ["echo", "hello world", "it's ok"].to_strings();
+  assert_eq!(command.shell_split(), expected_args);
+
+  // escaped spaces and quotes outside quoted runs, escaped double quotes inside them and empty quoted arguments
+  let command_2 = r#"cp my\ file.txt "say \"hi\"" '' "unterminated arg"#;
+  let expected_args_2 = ["cp", "my file.txt", r#"say "hi""#, "", "unterminated arg"].to_strings();
+  assert_eq!(command_2.shell_split(), expected_args_2);
+}
+
+#[test]
+fn test_strip_by_type_in_place() {
+  let sample_str = "Products: $9.99 per unit, £19.50 each.";
+  let mut owned_str = sample_str.to_string();
+  let capacity = owned_str.capacity();
+  owned_str.strip_by_type_in_place(CharType::Punctuation);
+  // the mutated string should match the output of the non-mutating method and reuse the same buffer
+  assert_eq!(owned_str, sample_str.strip_by_type(CharType::Punctuation));
+  assert_eq!(owned_str.capacity(), capacity);
+}
+
+#[test]
+fn test_enclose_escaping_set() {
+  let sample_str = r#"C:\Users\"Admin""#;
+  // both the closing double quote and the backslash escape character must be escaped
+  let expected_str = r#""C:\\Users\\\"Admin\"""#;
+  assert_eq!(sample_str.enclose_escaping_set('"', '"', '\\', &['\\']), expected_str);
+
+  // without the additional characters only the closing delimiter is escaped
+  assert_eq!("it's".enclose_escaping_set('\'', '\'', '\\', &[]), r#"'it\'s'"#);
+}
+
+#[test]
+fn test_unescape() {
+  let sample_str = r#"Tom whispered "I love you" as he gazed into Jennifer's eyes only inches away."#;
+  let quoted_str = sample_str.double_quotes_safe();
+  assert_eq!(quoted_str.strip_quotes_unescaped('\\'), sample_str);
+
+  // pre-escaped quotes are not escaped twice, so unescaping removes their escape characters too
+  let quoted_str_2 = r#"Bee's wax and \'organic honey\'"#.single_quotes_safe();
+  assert_eq!(quoted_str_2.strip_quotes_unescaped('\\'), "Bee's wax and 'organic honey'");
+
+  let sample_str_3 = r#"She wrote "From Antarctica with a Cold Heart""#;
+  let quoted_str_3 = sample_str_3.wrap_escaped('"', Some('"'));
+  assert_eq!(quoted_str_3.strip_quotes_unescaped('"'), sample_str_3);
+
+  assert_eq!(r#"a \\ b \" c"#.unescape('\\'), r#"a \ b " c"#);
+}
+
+#[test]
+fn test_enclose_each_line() {
+  let sample_str = "first line\nsecond line";
+  assert_eq!(sample_str.enclose_each_line("\"", "\""), "\"first line\"\n\"second line\"");
+
+  // Windows-style line breaks are preserved and blank lines are not wrapped
+  let sample_str_2 = "let a = 1;\r\n\r\nlet b = 2;\r\n";
+  assert_eq!(sample_str_2.enclose_each_line("/* ", " */"), "/* let a = 1; */\r\n\r\n/* let b = 2; */\r\n");
+}
+
+#[test]
+fn test_max_nesting_depth() {
+  assert_eq!("a(b(c)d)e".max_nesting_depth('(', ')'), Some(2));
+  assert_eq!("(a)(b)(c)".max_nesting_depth('(', ')'), Some(1));
+  assert_eq!("no brackets".max_nesting_depth('(', ')'), Some(0));
+  assert_eq!("{a: {b: {c: 1}}}".max_nesting_depth('{', '}'), Some(3));
+  // unbalanced inputs
+  assert_eq!("a(b(c)d".max_nesting_depth('(', ')'), None);
+  assert_eq!("a)b(".max_nesting_depth('(', ')'), None);
+}
+
+#[test]
+fn test_to_numbers_accounting() {
+  let sample_str = "Revenue 5,000.00, costs (1,234.56), see (note 3)";
+  assert_eq!(sample_str.to_numbers_accounting::<f64>(), vec![5000.0, -1234.56, 3.0]);
+  // the standard method ignores the parentheses
+  assert_eq!(sample_str.to_numbers::<f64>(), vec![5000.0, 1234.56, 3.0]);
+  assert_eq!("(42)".to_numbers_accounting::<i32>(), vec![-42]);
+}
+
+#[test]
+fn test_detect_number_format() {
+  let euro_format = NumberFormat::new(',', Some('.'));
+  assert_eq!("1.999,50".detect_number_format(), Some(euro_format));
+  assert_eq!("Il conto è 1.999,50€".detect_number_format(), Some(NumberFormat::euro()));
+  assert_eq!("1,999.50".detect_number_format(), Some(NumberFormat::standard()));
+  // repeated separators can only be grouping separators
+  assert_eq!("1.500.000".detect_number_format(), Some(NumberFormat::euro()));
+  assert_eq!("15,5kg".detect_number_format(), Some(NumberFormat::euro()));
+  // ambiguous or absent separators
+  assert_eq!("1.500".detect_number_format(), None);
+  assert_eq!("1500".detect_number_format(), None);
+}
+
+#[test]
+fn test_to_float_and_integer_strings() {
+  let sample_str = "2 apples cost 3.50";
+  assert_eq!(sample_str.to_integer_strings(), vec!["2".to_string()]);
+  assert_eq!(sample_str.to_float_strings(), vec!["3.50".to_string()]);
+  // thousand separators are removed before numbers are classified
+  let sample_str_2 = "1,500,000 units at 12.25 each, 3 boxes";
+  assert_eq!(sample_str_2.to_integer_strings(), ["1500000", "3"].to_strings());
+  assert_eq!(sample_str_2.to_float_strings(), ["12.25"].to_strings());
+}
+
+#[test]
+fn test_to_measurements() {
+  let sample_str = "2.5kg of flour and 3 L of water";
+  let expected = vec![(2.5, "kg".to_string()), (3.0, "L".to_string())];
+  assert_eq!(sample_str.to_measurements(), expected);
+
+  let input_text = "I'd like 2.5lb of flour please";
+  assert_eq!(input_text.to_measurements(), vec![(2.5, "lb".to_string())]);
+
+  // numbers without a unit yield an empty string
+  let sample_str_2 = "I retired at the age of 72.";
+  assert_eq!(sample_str_2.to_measurements(), vec![(72.0, "".to_string())]);
+
+  // trailing punctuation after a number stops the next word being read as a unit
+  let sample_str_3 = "Age 72, then 3 kg.";
+  assert_eq!(sample_str_3.to_measurements(), vec![(72.0, "".to_string()), (3.0, "kg".to_string())]);
+  let sample_str_4 = "I retired at 72. Then I left";
+  assert_eq!(sample_str_4.to_measurements(), vec![(72.0, "".to_string())]);
+}
+
+#[test]
+fn test_unicode_punctuation() {
+  let sample_str = "«café»…";
+  assert_eq!(sample_str.strip_by_type(CharType::UnicodePunctuation), "café");
+  // the ASCII-only variant leaves guillemets and ellipses intact
+  assert_eq!(sample_str.strip_by_type(CharType::Punctuation), sample_str);
+
+  let sample_str_2 = "¿Qué? “Sí”, dijo.";
+  assert_eq!(sample_str_2.strip_by_type(CharType::UnicodePunctuation), "Qué Sí dijo");
+}
+
+#[test]
+fn test_horizontal_space_and_newline_types() {
+  let sample_str = "a \t b\nc";
+  assert_eq!(sample_str.strip_by_type(CharType::HorizontalSpace), "ab\nc");
+  assert_eq!(sample_str.strip_by_type(CharType::Newline), "a \t bc");
+  // the broader Spaces type removes line breaks too
+  assert_eq!(sample_str.strip_by_type(CharType::Spaces), "abc");
+}
+
+#[test]
+fn test_case_insensitive_ascii_fast_path() {
+  // the ASCII fast path should yield the same results as lower-casing both strings
+  let samples = ["Jan_2023_IMAGES", "café-Zürich", "ΣΟΦΙΑ report", "plain ascii", "", "İstanbul"];
+  let patterns = ["jan", "images", "2023", "ZÜRICH", "café", "σοφια", "REPORT", "", "x", "istanbul"];
+  for sample in samples {
+    let lower_sample = sample.to_lowercase();
+    for pattern in patterns {
+      let lower_pattern = pattern.to_lowercase();
+      assert_eq!(sample.starts_with_ci(pattern), lower_sample.starts_with(&lower_pattern));
+      assert_eq!(sample.ends_with_ci(pattern), lower_sample.ends_with(&lower_pattern));
+      assert_eq!(sample.contains_ci(pattern), lower_sample.contains(&lower_pattern));
+      assert_eq!(sample.equals_ci(pattern), lower_sample == lower_pattern);
+      let rule = [StringBounds::Contains(pattern, true, CaseMatchMode::Insensitive)];
+      assert_eq!(sample.match_all_conditional(&rule), lower_sample.contains(&lower_pattern));
+    }
+  }
+  assert!("Jan_2023_IMAGES".contains_ci_alphanum("2023images"));
+  assert!("Start-Up".equals_ci_alphanum("START up"));
+  // the _ci_alphanum methods should match lower-casing before stripping non-alphanumeric characters
+  let samples = ["İstanbul", "İ", "Jan_2023_IMAGES", "café-Zürich"];
+  let patterns = ["İstanbul", "İ", "istanbul", "i̇stanbul", "images", "CAFÉ", "zürich", ""];
+  for sample in samples {
+    let base = sample.to_lowercase().strip_non_alphanum();
+    for pattern in patterns {
+      let lower_pattern = pattern.to_lowercase();
+      assert_eq!(sample.equals_ci_alphanum(pattern), base == lower_pattern.strip_non_alphanum());
+      assert_eq!(sample.starts_with_ci_alphanum(pattern), base.starts_with(&lower_pattern));
+      assert_eq!(sample.ends_with_ci_alphanum(pattern), base.ends_with(&lower_pattern));
+      assert_eq!(sample.contains_ci_alphanum(pattern), base.contains(&lower_pattern));
+    }
+  }
+  assert!("İ".equals_ci_alphanum("İ"));
+  assert!("İstanbul".equals_ci_alphanum("İstanbul"));
+}
+
+#[test]
+fn test_correct_numeric_string_cow() {
+  use std::borrow::Cow;
+  let corrected = "15.00".correct_numeric_string_cow(false);
+  assert!(matches!(corrected, Cow::Borrowed("15.00")));
+
+  let corrected_2 = "15,00".correct_numeric_string_cow(true);
+  assert!(matches!(corrected_2, Cow::Owned(_)));
+  assert_eq!(corrected_2, "15.00");
+
+  // the results should always match the allocating method
+  for num_str in ["1,999.50", "1.999,50", "1500", "1.500", "-12.5"] {
+    for enforce_comma in [false, true] {
+      assert_eq!(num_str.correct_numeric_string_cow(enforce_comma), num_str.correct_numeric_string(enforce_comma));
+    }
+  }
+}
+
+#[test]
+fn test_is_digits_only_empty() {
+  // empty strings should not validate as digit strings
+  assert!(!"".is_digits_only());
+  assert!(!"".is_digits_only_radix(16));
+  assert!("0".is_digits_only());
+  assert!("ff".is_digits_only_radix(16));
+}
+
+#[test]
+fn test_absent_separator_head_tail() {
+  let source_str = "nodelimiter";
+  // the head is the whole string and the tail is empty if the separator is absent
+  assert_eq!(source_str.to_head(","), source_str);
+  assert_eq!(source_str.to_tail(","), "");
+  assert_eq!(source_str.to_head_tail(","), (source_str.to_string(), "".to_string()));
+  assert_eq!(source_str.to_remainder_end(","), "");
+  assert_eq!(source_str.to_head_tail_on_any_char(&[',', ';']), (source_str.to_string(), "".to_string()));
+  // a leading separator yields an empty head
+  assert_eq!(",a,b".to_head_tail(","), ("".to_string(), "a,b".to_string()));
+  assert_eq!(",a,b".to_tail(","), "a,b");
+  assert_eq!(",a,b".to_remainder_end(","), "b");
+}
+
+#[test]
+fn test_split_first_last() {
+  let source_str = "comma,separated,string";
+  assert_eq!(source_str.split_first(","), ("comma".to_string(), "separated,string".to_string()));
+  assert_eq!(source_str.split_last(","), ("comma,separated".to_string(), "string".to_string()));
+  assert_eq!(source_str.split_first_ref(","), ("comma", "separated,string"));
+  assert_eq!(source_str.split_last_ref(","), ("comma,separated", "string"));
+  assert_eq!("one-item".split_last_ref(","), ("one-item", ""));
+}
+
+#[test]
+fn test_starts_with_count_type() {
+  let file_names = ["2024-report.pdf", "24-summary.pdf", "20240512_scan.png", "report.2024"];
+  let dated_files: Vec<&str> = file_names.into_iter().filter(|name| name.starts_with_count_type(CharType::DecDigit, 4)).collect();
+  assert_eq!(dated_files, vec!["2024-report.pdf", "20240512_scan.png"]);
+
+  assert!("report.2024".ends_with_count_type(CharType::DecDigit, 4));
+  assert!(!"report.24".ends_with_count_type(CharType::DecDigit, 4));
+  // shorter strings cannot have a long enough run
+  assert!(!"123".starts_with_count_type(CharType::DecDigit, 4));
+}
+
+#[test]
+fn test_leading_and_trailing_runs() {
+  let sample_str = "123abc";
+  assert_eq!(sample_str.leading_run(CharType::DecDigit), "123");
+  assert_eq!(sample_str.trailing_run(CharType::Alpha), "abc");
+  // the run is empty if the boundary character does not match
+  assert_eq!(sample_str.leading_run(CharType::Alpha), "");
+  assert_eq!(sample_str.trailing_run(CharType::DecDigit), "");
+  // multibyte characters
+  assert_eq!("Zürich 8001".leading_run(CharType::Alpha), "Zürich");
+  assert_eq!("8001 Zürich".trailing_run(CharType::Alpha), "Zürich");
+  assert_eq!("2024".leading_run(CharType::DecDigit), "2024");
+}
+
+#[test]
+fn test_split_at_type_boundaries() {
+  let types = [CharType::Alpha, CharType::DecDigit];
+  assert_eq!("abc123".split_at_type_boundaries(&types), ["abc", "123"].to_strings());
+  assert_eq!("ab12cd".split_at_type_boundaries(&types), ["ab", "12", "cd"].to_strings());
+  assert_eq!("a1b2".split_at_type_boundaries(&types), ["a", "1", "b", "2"].to_strings());
+  // unclassified characters are grouped into their own segments
+  assert_eq!("v2.10-beta".split_at_type_boundaries(&types), ["v", "2", ".", "10", "-", "beta"].to_strings());
+}
+
+#[test]
+fn test_to_split_options() {
+  let sample_str = "a, , b ,";
+  assert_eq!(sample_str.to_split(",", SplitOptions::default()), ["a", " ", " b ", ""].to_strings());
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(true, false, None)), ["a", "", "b", ""].to_strings());
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(false, true, None)), ["a", " ", " b "].to_strings());
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(true, true, None)), ["a", "b"].to_strings());
+  // with a limit the last part contains the unsplit remainder
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(false, false, Some(2))), ["a", " , b ,"].to_strings());
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(true, true, Some(2))), ["a", "b ,"].to_strings());
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(true, true, Some(1))), ["a, , b ,"].to_strings());
+  // the presets match the dedicated methods
+  assert_eq!(sample_str.to_split(",", SplitOptions::default()), sample_str.to_parts(","));
+  assert_eq!(sample_str.to_split(",", SplitOptions::new(false, true, None)), sample_str.to_segments(","));
+}
+
+#[test]
+fn test_score_conditional() {
+  let weighted_rules = [
+    (StringBounds::Contains("urgent", true, CaseMatchMode::Insensitive), 5.0),
+    (StringBounds::Contains("fyi", true, CaseMatchMode::Insensitive), -2.0),
+    (StringBounds::StartsWith("re:", true, CaseMatchMode::Insensitive), 1.5),
+  ];
+  // matches the first and last rules only
+  assert_eq!("RE: Urgent invoice".score_conditional(&weighted_rules), 6.5);
+  assert_eq!("FYI: newsletter".score_conditional(&weighted_rules), -2.0);
+
+  let subjects = [
+    "RE: Urgent invoice",
+    "FYI: newsletter",
+    "Urgent: FYI server restart",
+    "Lunch plans",
+  ];
+  assert_eq!(subjects.filter_by_score(&weighted_rules, 2.0), vec!["RE: Urgent invoice", "Urgent: FYI server restart"]);
+  assert_eq!(subjects.to_strings().filter_by_score(&weighted_rules, 0.0).len(), 2);
+}
+
+#[test]
+fn test_to_numbers_with_space_grouping() {
+  let french_format = NumberFormat::new(',', Some(' '));
+  assert_eq!("Total : 1 234 567,89 €".to_numbers_with_format::<f64>(&french_format), vec![1234567.89]);
+  // non-breaking spaces are treated like normal spaces
+  assert_eq!("1\u{a0}234,50 et 12,5".to_numbers_with_format::<f64>(&french_format), vec![1234.5, 12.5]);
+  // spaces between numbers that are not groups of three digits remain boundaries
+  assert_eq!("années 2023 24 et 1 000".to_numbers_with_format::<u32>(&french_format), vec![2023, 24, 1000]);
+  assert_eq!("-1 500,25".to_numeric_strings_with_format(&french_format), vec!["-1500.25".to_string()]);
+  // the standard format ignores space grouping
+  assert_eq!("1,234,567.89 and 3".to_numbers_with_format::<f64>(&NumberFormat::standard()), vec![1234567.89, 3.0]);
+}
+
+#[test]
+fn test_is_blank() {
+  assert!("".is_blank());
+  assert!("   \t\n".is_blank());
+  assert!(!" x ".is_blank());
+  assert!(!"".is_whitespace_only());
+  assert!("   \t\n".is_whitespace_only());
+  assert_eq!(" x ".first_non_whitespace(), Some('x'));
+  assert_eq!("   \t\n".first_non_whitespace(), None);
+}
+
+#[test]
+fn test_segments_to_title() {
+  assert_eq!("user_profile_settings".segments_to_title("_", " "), "User Profile Settings");
+  assert_eq!("my-blog-post".segments_to_title("-", " "), "My Blog Post");
+  // leading, trailing and repeated separators are ignored
+  assert_eq!("/docs//getting-started/".segments_to_title("/", " › "), "Docs › Getting-started");
+}
+
+#[test]
+fn test_abbreviate() {
+  assert_eq!("North Atlantic Treaty Organization".abbreviate(), "NATO");
+  assert_eq!("north atlantic treaty organization".abbreviate(), "NATO");
+  // skip short stop-words
+  assert_eq!("Department of Defense".abbreviate_with(3), "DD");
+  assert_eq!("\"Quick\", (brown) fox - it's lazy!".to_words(), ["Quick", "brown", "fox", "it's", "lazy"].to_strings());
+}
+
+#[test]
+fn test_to_numbers_with_source() {
+  let sample = "Total: 1.234,5 EUR after a discount of 10,25 EUR";
+  let pairs = sample.to_numbers_with_source::<f64>();
+  assert_eq!(pairs, vec![(1234.5, "1.234,5".to_string()), (10.25, "10,25".to_string())]);
+  // ambiguous thousand separators require European mode
+  let pairs = "Fee: 1.500 EUR".to_numbers_with_source_conditional::<u32>(true);
+  assert_eq!(pairs, vec![(1500, "1.500".to_string())]);
+}
+
+#[test]
+fn test_remove_consecutive_duplicates() {
+  assert_eq!("loooong".remove_consecutive_duplicates(), "long");
+  assert_eq!("mississippi".remove_consecutive_duplicates(), "misisipi");
+  assert_eq!("mississippi".remove_duplicate_chars(), "misp");
+  // only collapse repeated slashes
+  assert_eq!("a//b///c".remove_consecutive_duplicates_of('/'), "a/b/c");
+  assert_eq!("/var//www///app.css".remove_consecutive_duplicates_of('/'), "/var/www/app.css");
+}
+
+#[test]
+fn test_common_prefix_and_suffix() {
+  let words = ["interspecies", "interstellar", "interstate"];
+  assert_eq!(words.common_prefix(), "inters");
+  let paths = ["/var/www/app/styles.css", "/var/www/app/main.js"].to_strings();
+  assert_eq!(paths.common_prefix(), "/var/www/app/");
+  let files = ["report_2023.csv", "summary_2023.csv"];
+  assert_eq!(files.common_suffix(), "_2023.csv");
+  // multibyte characters are compared whole
+  assert_eq!(["café", "cafè"].common_prefix(), "caf");
+  let empty: [&str; 0] = [];
+  assert_eq!(empty.common_prefix(), "");
+  assert_eq!(["single"].common_suffix(), "single");
+}
+
+#[test]
+fn test_longest_common_substring() {
+  assert_eq!("abcdef".longest_common_substring("zcdemf"), "cde");
+  // the first of equally long runs wins
+  assert_eq!("abxcd".longest_common_substring("cdyab"), "ab");
+  assert_eq!("naïve café".longest_common_substring("le café"), "e café");
+  assert_eq!("abc".longest_common_substring("xyz"), "");
+  assert_eq!("".longest_common_substring("abc"), "");
+}
+
+#[test]
+fn test_reverse_chars() {
+  assert_eq!("stressed".reverse_chars(), "desserts");
+  assert_eq!("café".reverse_chars(), "éfac");
+  // a decomposed ë, i.e. e followed by a combining diaeresis, is split by char reversal
+  let decomposed = "noe\u{308}l";
+  assert_eq!(decomposed.reverse_chars(), "l\u{308}eon");
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_reverse_graphemes() {
+  let decomposed = "noe\u{308}l";
+  // the diaeresis stays on the e
+  assert_eq!(decomposed.reverse_graphemes(), "le\u{308}on");
+  assert_ne!(decomposed.reverse_graphemes(), decomposed.reverse_chars());
+}
+
+#[test]
+fn test_first_and_last_segment_opt() {
+  assert_eq!("nodelim".first_segment_opt("/"), None);
+  assert_eq!("nodelim".last_segment_opt("/"), None);
+  // the lenient variants return the whole string
+  assert_eq!("nodelim".to_first("/"), "nodelim");
+  assert_eq!("/var/www/app".first_segment_opt("/"), Some("var".to_string()));
+  assert_eq!("/var/www/app/".last_segment_opt("/"), Some("app".to_string()));
+}
+
+#[test]
+fn test_count_words_matching() {
+  let sentence = "Sally sells seashells by the seashore, said Sam.";
+  let rules = bounds_builder().starting_with_ci("s");
+  assert_eq!(sentence.count_words_matching(&rules), 6);
+  let rules = bounds_builder().starting_with_cs("S").not_ending_with_ci("m");
+  assert_eq!(sentence.count_words_matching(&rules), 1);
+}
+
+#[test]
+fn test_pad_numbers() {
+  assert_eq!("img9,img10".pad_numbers(4), "img0009,img0010");
+  assert_eq!("file2".pad_numbers(4), "file0002");
+  // longer numbers and text without digits are unchanged
+  assert_eq!("v123456-beta".pad_numbers(3), "v123456-beta");
+  assert_eq!("no digits".pad_numbers(3), "no digits");
+  let mut names = ["file10", "file2", "file1"].map(|name| name.pad_numbers(3));
+  names.sort();
+  assert_eq!(names, ["file001", "file002", "file010"]);
+}
+
+#[test]
+fn test_wrap_lines() {
+  let text = "The quick brown fox jumps over the lazy dog near the riverbank";
+  let wrapped = text.wrap_lines(20);
+  assert_eq!(wrapped, "The quick brown fox\njumps over the lazy\ndog near the\nriverbank");
+  assert!(wrapped.lines().all(|line| line.chars().count() <= 20));
+  // explicit newlines are paragraph boundaries
+  assert_eq!("one two three\n\nfour five".wrap_lines(8), "one two\nthree\n\nfour\nfive");
+  // words longer than the width are broken
+  assert_eq!("a supercalifragilistic word".wrap_lines(10), "a\nsupercalif\nragilistic\nword");
+}
+
+#[test]
+fn test_strip_html_tags() {
+  assert_eq!("<p>Hello <b>world</b></p>".strip_html_tags(), "Hello world");
+  let snippet = "<div class=\"intro\">\n  <h1>Title</h1>\n  <p>First<br/>line</p>\n</div>";
+  assert_eq!(snippet.strip_html_tags().normalize_whitespace(), "Title Firstline");
+  // comparisons and unterminated brackets are kept
+  assert_eq!("3 < 4 and <b>bold".strip_html_tags(), "3 < 4 and bold");
+  assert_eq!("trailing <unterminated".strip_html_tags(), "trailing <unterminated");
+  assert_eq!("  multiple \t spaces\nand lines ".normalize_whitespace(), "multiple spaces and lines");
+}
+
+#[test]
+fn test_between_first_last() {
+  assert_eq!("func(a, b, (c))".between_first_last("(", ")"), Some("a, b, (c)".to_string()));
+  assert_eq!("<<inner>>".between_first_last("<<", ">>"), Some("inner".to_string()));
+  assert_eq!("no brackets".between_first_last("(", ")"), None);
+  assert_eq!("open( only".between_first_last("(", ")"), None);
+  assert_eq!(") reversed (".between_first_last("(", ")"), None);
+}
+
+#[test]
+fn test_to_numbers_signed_currency() {
+  assert_eq!("-$5.00 refund".to_numbers::<f64>(), vec![-5.0]);
+  assert_eq!("Balance: -€1.250,50".to_first_number_euro::<f64>(), Some(-1250.5));
+  assert_eq!("-£20 and $30".to_numbers::<i32>(), vec![-20, 30]);
+  // other characters between the sign and digits do not make it negative
+  assert_eq!("- $5 and -x7".to_numbers::<i32>(), vec![5, 7]);
+  assert_eq!("-$1 234,50".to_numbers_with_format::<f64>(&NumberFormat::new(',', Some(' '))), vec![-1234.5]);
+}
+
+#[test]
+fn test_split_keep_trailing() {
+  assert_eq!("a.b.c".split_keep_trailing(&['.']), vec!["a.", "b.", "c"]);
+  assert_eq!("Hello. How are you? Fine!".split_keep_trailing(&['.', '?', '!']), vec!["Hello.", " How are you?", " Fine!"]);
+  // a trailing delimiter stays on the last segment
+  assert_eq!("a.b.".split_keep_trailing(&['.']), vec!["a.", "b."]);
+  assert_eq!("no delimiter".split_keep_trailing(&['.']), vec!["no delimiter"]);
+}
+
+#[test]
+fn test_to_sentence_case() {
+  assert_eq!("HELLO. HOW ARE YOU?".to_sentence_case(), "Hello. How are you?");
+  assert_eq!("HELLO WORLD".to_sentence_case(), "Hello world");
+  assert_eq!("wOW!! \"REALLY?\" yes...\nSURE".to_sentence_case(), "Wow!! \"Really?\" Yes...\nSure");
+  // decimal points do not end sentences
+  assert_eq!("PI IS 3.14. NICE".to_sentence_case(), "Pi is 3.14. Nice");
+  assert_eq!("First one. Second one!  Third?".to_sentences(), vec!["First one.", "Second one!", "Third?"]);
+}
+
+#[test]
+fn test_char_at_and_char_range() {
+  let word = "café";
+  assert_eq!(word.char_at(3), Some('é'));
+  assert_eq!(word.char_at(-1), Some('é'));
+  assert_eq!(word.char_at(-4), Some('c'));
+  assert_eq!(word.char_at(4), None);
+  assert_eq!(word.char_at(-5), None);
+  assert_eq!(word.char_range(1, 4), Some("afé".to_string()));
+  assert_eq!(word.char_range(-2, 4), Some("fé".to_string()));
+  assert_eq!(word.char_range(1, -1), Some("af".to_string()));
+  assert_eq!(word.char_range(2, 2), Some("".to_string()));
+  assert_eq!(word.char_range(3, 1), None);
+  assert_eq!(word.char_range(0, 5), None);
+  // extreme indices are out of range rather than overflowing
+  assert_eq!(word.char_at(i32::MIN), None);
+  assert_eq!(word.char_at(i32::MAX), None);
+  assert_eq!(word.char_range(i32::MIN, -1), None);
+  assert_eq!(word.char_range(0, i32::MAX), None);
+}
+
+#[test]
+fn test_truthy_and_falsy() {
+  for truthy in ["yes", "Y", "TRUE", "ok", "1", " On "] {
+    assert!(truthy.is_truthy());
+  }
+  for falsy in ["NO", "FALSE", "n", "0", "off"] {
+    assert!(falsy.is_falsy());
+  }
+  // words only containing the patterns are not recognised
+  assert_eq!("Noon".to_bool(), None);
+  assert_eq!("not at all".to_bool(), None);
+  assert!(!"maybe".is_truthy() && !"maybe".is_falsy());
+  let words = BoolStrings::new(&["ja", "oui"], &["nein", "non"]);
+  assert_eq!("Oui".to_bool_with(&words), Some(true));
+  assert_eq!("NEIN".to_bool_with(&words), Some(false));
+  assert_eq!("yes".to_bool_with(&words), None);
+}
+
+#[test]
+fn test_split_where() {
+  assert_eq!("a1!b2?c".split_where(|c| !c.is_alphanumeric()), vec!["a1", "b2", "c"]);
+  // leading, trailing and repeated separators do not yield empty segments
+  assert_eq!("--alpha__beta--".split_where(|c| c == '-' || c == '_'), vec!["alpha", "beta"]);
+  assert_eq!("room 12 floor 3".split_where(|c| !c.is_ascii_digit()), vec!["12", "3"]);
+}
+
+#[test]
+fn test_matched_prefix_and_suffix_ci() {
+  let extensions = [".jpg", ".png"];
+  assert_eq!("image.PNG".matched_suffix_ci(&extensions), Some(".png"));
+  assert_eq!("photo.Jpg".matched_suffix_ci(&extensions), Some(".jpg"));
+  assert_eq!("document.pdf".matched_suffix_ci(&extensions), None);
+  // the first matching pattern wins
+  assert_eq!("HTTPS://example.com".matched_prefix_ci(&["http", "https"]), Some("http"));
+  assert_eq!("ftp://example.com".matched_prefix_ci(&["http", "https"]), None);
+}
+
+#[test]
+fn test_distinct_chars() {
+  assert_eq!("banana".distinct_chars(), vec!['b', 'a', 'n']);
+  assert_eq!("banana".distinct_chars_sorted(), vec!['a', 'b', 'n']);
+  assert_eq!("naïveté".distinct_chars(), vec!['n', 'a', 'ï', 'v', 'e', 't', 'é']);
+  assert!("".distinct_chars().is_empty());
+}
+
+#[test]
+fn test_only_contains_types() {
+  assert!("Hello World".only_contains_types(&[CharType::Alpha, CharType::Spaces]));
+  assert!(!"Hello!".only_contains_types(&[CharType::Alpha, CharType::Spaces]));
+  assert!("20240131".only_contains_type(CharType::DecDigit));
+  assert!(!"2024-01-31".only_contains_type(CharType::DecDigit));
+  // empty strings contain no disallowed characters
+  assert!("".only_contains_type(CharType::Alpha));
+}
+
+#[test]
+fn test_containing_word_rules() {
+  let lines = [
+    "The cat sat on the mat",
+    "Browse by category",
+    "CAT-flap for sale",
+    "Concatenate strings",
+    "A dog and a Cat",
+  ];
+  let rules = bounds_builder().containing_word_ci("cat");
+  assert_eq!(lines.filter_all_rules(&rules), vec!["The cat sat on the mat", "CAT-flap for sale", "A dog and a Cat"]);
+  let rules = bounds_builder().containing_word_cs("Cat");
+  assert_eq!(lines.filter_all_rules(&rules), vec!["A dog and a Cat"]);
+  let rules = bounds_builder().or_containing_word_ci(&["dog", "category"]);
+  assert_eq!(lines.filter_all_rules(&rules), vec!["Browse by category", "A dog and a Cat"]);
+  let rules = bounds_builder().containing_ci("cat").not_containing_word_ci("cat");
+  assert_eq!(lines.filter_all_rules(&rules), vec!["Browse by category", "Concatenate strings"]);
+  // accented text falls back to Unicode lower-casing
+  assert!("Él está en el café".match_all_conditional(&[StringBounds::ContainsWord("CAFÉ", true, CaseMatchMode::Insensitive)]));
+}
+
+#[test]
+fn test_to_segment_or() {
+  let path = "/User/maria/Documents";
+  assert_eq!(path.to_segment_or("/", 1, "guest"), "maria");
+  assert_eq!(path.to_segment_or("/", 5, "guest"), "guest");
+  assert_eq!(path.to_segment_or("/", -4, "guest"), "guest");
+  let source = "pictures/holiday-france-1983/originals";
+  assert_eq!(source.to_inner_segment_or(&[("/", 1), ("-", 2)], "unknown"), "1983");
+  assert_eq!(source.to_inner_segment_or(&[("/", 1), ("-", 3)], "unknown"), "unknown");
+}
+
+#[test]
+fn test_split_on_any_char_with_delims() {
+  let parts = "a-b_c".split_on_any_char_with_delims(&['-', '_']);
+  assert_eq!(parts, vec![("a".to_string(), Some('-')), ("b".to_string(), Some('_')), ("c".to_string(), None)]);
+  // empty parts are kept so the string can be rebuilt
+  let parts = "x––y".split_on_any_char_with_delims(&['–']);
+  assert_eq!(parts, vec![("x".to_string(), Some('–')), ("".to_string(), Some('–')), ("y".to_string(), None)]);
+  assert_eq!("plain".split_on_any_char_with_delims(&['-']), vec![("plain".to_string(), None)]);
+}
+
+#[test]
+fn test_equals_normalized() {
+  assert!("Résumé".equals_normalized("resume"));
+  assert!("ÉCOLE".equals_normalized("école"));
+  // decomposed accents and ligatures
+  assert!("Cafe\u{301}".equals_normalized("café"));
+  assert!("Straße".equals_normalized("STRASSE"));
+  assert!(!"resume".equals_normalized("resumes"));
+  assert_eq!("Crème brûlée à Łódź".strip_diacritics(), "Creme brulee a Lodz");
+  let cities = ["São Paulo", "Sao Bernardo", "Zürich", "Bogotá"];
+  let rules = [StringBounds::StartsWith("sao", true, CaseMatchMode::Normalized)];
+  assert_eq!(cities.filter_all_conditional(&rules), vec!["São Paulo", "Sao Bernardo"]);
+  let rules = [StringBounds::Whole("zurich", true, CaseMatchMode::Normalized)];
+  assert_eq!(cities.filter_all_conditional(&rules), vec!["Zürich"]);
+}
+
+#[test]
+fn test_to_first_n_numbers() {
+  let sample = "12/34/56 extra text with 78 more numbers like 90 and 1,234.5";
+  assert_eq!(sample.to_first_n_numbers::<i32>(2), vec![12, 34]);
+  assert_eq!(sample.to_first_n_numbers::<f64>(10), sample.to_numbers::<f64>());
+  assert!(sample.to_first_n_numbers::<i32>(0).is_empty());
+  // numbers that cannot be parsed as the target type are not counted
+  assert_eq!("3.5 then 4 and 5".to_first_n_numbers::<u8>(2), vec![4, 5]);
+}
+
+#[test]
+fn test_join_prefixed() {
+  assert_eq!(["a", "b", "c"].join_prefixed("- ", "\n"), "- a\n- b\n- c");
+  let flags = "verbose,quiet".to_segments(",");
+  assert_eq!(flags.join_prefixed("--", " "), "--verbose --quiet");
+  let empty: Vec<String> = Vec::new();
+  assert_eq!(empty.join_prefixed("- ", "\n"), "");
+}
+
+#[test]
+fn test_has_normalized_whitespace() {
+  assert!("a b c".has_normalized_whitespace());
+  assert!("single".has_normalized_whitespace());
+  assert!("".has_normalized_whitespace());
+  assert!(!"a  b".has_normalized_whitespace());
+  assert!(!" a".has_normalized_whitespace());
+  assert!(!"a ".has_normalized_whitespace());
+  assert!(!"a\tb".has_normalized_whitespace());
+  let messy = " a \n b ";
+  assert!(messy.normalize_whitespace().has_normalized_whitespace());
+}
+
+#[test]
+fn test_to_numbers_radix() {
+  assert_eq!("colors #ff9900 and 0x1F".to_hex_numbers::<u32>(), vec![0xff9900, 0x1F]);
+  assert_eq!("mask 0xFF, offset 0X1f".to_hex_numbers::<u8>(), vec![255, 31]);
+  // words made of hex letters only are ignored without a prefix
+  assert_eq!("a bad cafe at #fff".to_hex_numbers::<u16>(), vec![0xfff]);
+  assert_eq!("flags 0b1010 and 0b11 but not 0b12".to_binary_numbers::<u8>(), vec![10, 3]);
+  assert_eq!("mode 0o755".to_numbers_radix::<u32>(8), vec![0o755]);
+  // values out of range for the integer type are skipped
+  assert_eq!("0x1FF 0x10".to_hex_numbers::<u8>(), vec![16]);
+  // bare decimal tokens are read in the higher base
+  assert_eq!("0xFF and 10 items".to_hex_numbers::<u32>(), vec![255, 16]);
+  // unsupported bases yield no numbers rather than panicking
+  assert!("10 and 11".to_numbers_radix::<u32>(0).is_empty());
+  assert!("10 and 11".to_numbers_radix::<u32>(1).is_empty());
+  assert!("10 and 11".to_numbers_radix::<u32>(37).is_empty());
+  assert_eq!("10 and z1".to_numbers_radix::<u32>(36), vec![36, 1261]);
+}
+
+#[test]
+fn test_wrap_if() {
+  assert_eq!("London, UK".quote_if_needed(','), "\"London, UK\"");
+  assert_eq!("London".quote_if_needed(','), "London");
+  assert_eq!("New York".quote_if_needed(','), "\"New York\"");
+  assert_eq!("5\" pipe".quote_if_needed(','), "\"5\\\" pipe\"");
+  let cells = ["id", "full name", "notes;misc"];
+  let row = cells.iter().map(|cell| cell.wrap_if('[', |txt| txt.contains(' '))).collect::<Vec<String>>();
+  assert_eq!(row, vec!["id", "[full name]", "notes;misc"]);
+}
+
+#[test]
+fn test_strip_by_type_counted() {
+  let (stripped, num_removed) = "Hello, world! How's it going?".strip_by_type_counted(CharType::Punctuation);
+  assert_eq!(stripped, "Hello world Hows it going");
+  assert_eq!(num_removed, 4);
+  assert_eq!("clean".strip_by_type_counted(CharType::Punctuation), ("clean".to_string(), 0));
+  // counts characters rather than bytes
+  assert_eq!("café42".strip_by_type_counted(CharType::Alpha), ("42".to_string(), 4));
+}
+
+#[test]
+fn test_to_segments_quoted() {
+  assert_eq!(r#"a/"b/c"/d"#.to_segments_quoted("/", '"'), vec!["a", "b/c", "d"]);
+  // empty segments are dropped as with to_segments
+  assert_eq!(r#"/a//'b//c'/"#.to_segments_quoted("/", '\''), vec!["a", "b//c"]);
+  assert_eq!(r#"one :: "two :: three" :: four"#.to_segments_quoted(" :: ", '"'), vec!["one", "two :: three", "four"]);
+  assert_eq!("a/b".to_segments_quoted("/", '"'), "a/b".to_segments("/"));
+}
+
+#[test]
+fn test_similarity_ratio() {
+  assert_eq!("kitten".edit_distance("sitting"), 3);
+  assert!(("kitten".similarity_ratio("sitting") - 4.0 / 7.0).abs() < 1e-9);
+  assert_eq!("".similarity_ratio(""), 1.0);
+  assert_eq!("abc".similarity_ratio(""), 0.0);
+  assert_eq!("same".similarity_ratio("same"), 1.0);
+  // lengths are measured in characters
+  assert_eq!("café".edit_distance("cafe"), 1);
+  assert_eq!("café".similarity_ratio("cafe"), 0.75);
+}
+
+#[test]
+fn test_first_match_of() {
+  assert_eq!("a=b;c".first_match_of(&["=", ";"]), Some((1, 0)));
+  assert_eq!("a;b=c".first_match_of(&["=", ";"]), Some((1, 1)));
+  // the earlier pattern wins a tie at the same offset
+  assert_eq!("x<=y".first_match_of(&["<", "<="]), Some((1, 0)));
+  assert_eq!("x<=y".first_match_of(&["<=", "<"]), Some((1, 0)));
+  // offsets are in bytes
+  assert_eq!("é=1".first_match_of(&["="]), Some((2, 0)));
+  assert_eq!("plain".first_match_of(&["=", ""]), None);
+}
+
+#[test]
+fn test_replace_segments() {
+  assert_eq!("a/b/c/d".replace_segments("/", 1, 3, "X"), "a/X/d");
+  assert_eq!("a/b/c/d".replace_segments("/", 0, 1, "X"), "X/b/c/d");
+  // negative indices count from the end and the end index is clamped
+  assert_eq!("a/b/c/d".replace_segments("/", -2, 10, "X"), "a/b/X");
+  assert_eq!("a/b/c/d".replace_segments("/", 1, -1, "X"), "a/X/d");
+  assert_eq!("a.b.c".replace_segments(".", -10, 10, "all"), "all");
+  // an empty range leaves the string unchanged
+  assert_eq!("a/b/c".replace_segments("/", 2, 1, "X"), "a/b/c");
+}
+
+#[test]
+fn test_to_numbers_scaled() {
+  assert_eq!("1.5k views from 2M users".to_numbers_scaled(), vec![1500.0, 2_000_000.0]);
+  assert_eq!("Revenue: $3.2B (up from $900M)".to_numbers_scaled(), vec![3_200_000_000.0, 900_000_000.0]);
+  // bare numbers and other units are not scaled
+  assert_eq!("42 items weighing 5kg over 5m".to_numbers_scaled(), vec![42.0, 5.0, 5.0]);
+}
+
+#[test]
+fn test_word_positions() {
+  assert_eq!("the cat".word_positions(), vec![("the".to_string(), 0..3), ("cat".to_string(), 4..7)]);
+  let text = "  (Café) au lait!";
+  let positions = text.word_positions();
+  assert_eq!(positions, vec![("Café".to_string(), 3..8), ("au".to_string(), 10..12), ("lait".to_string(), 13..17)]);
+  // the ranges slice the original string
+  assert!(positions.iter().all(|(word, range)| &text[range.clone()] == word));
+  assert_eq!(text.to_words(), vec!["Café", "au", "lait"]);
+}
+
+#[test]
+fn test_replace_map() {
+  let pairs = [("USA", "United States"), ("UK", "United Kingdom"), ("United", "UNITED")];
+  let sentence = "Flights from the USA to the UK";
+  // replaced text is not expanded again
+  assert_eq!(sentence.replace_map(&pairs), "Flights from the United States to the United Kingdom");
+  // the longest key wins at the same position
+  let pairs = [("app", "application"), ("apple", "fruit")];
+  assert_eq!("apple app".replace_map(&pairs), "fruit application");
+  let pairs = [("usa", "United States"), ("é", "e")];
+  assert_eq!("From the Usa to CAFÉ".replace_map_ci(&pairs), "From the United States to CAFe");
+}
+
+#[test]
+fn test_reverse_segments() {
+  assert_eq!("com.example.app".reverse_segments("."), "app.example.com");
+  assert_eq!("com.example.app".reverse_parts("."), "app.example.com");
+  // empty segments are dropped or kept
+  assert_eq!("/a//b/".reverse_segments("/"), "b/a");
+  assert_eq!("/a//b".reverse_parts("/"), "b//a/");
+  assert_eq!("single".reverse_segments("."), "single");
+}
+
+#[test]
+fn test_classify_char() {
+  let to_names = |c: char| classify_char(c).iter().map(|ct| format!("{:?}", ct)).collect::<Vec<String>>();
+  assert_eq!(to_names('A'), ["AlphaNum", "Upper", "Alpha"]);
+  assert_eq!(to_names('5'), ["DecDigit", "Numeric", "AlphaNum"]);
+  assert_eq!(to_names('.'), ["Punctuation", "UnicodePunctuation"]);
+  assert_eq!(to_names(' '), ["Spaces", "HorizontalSpace"]);
+}
+
+#[test]
+fn test_trim_by_type() {
+  let sample = "...hello!!!";
+  assert_eq!(sample.trim_by_type(CharType::Punctuation), "hello");
+  assert_eq!(sample.trim_start_by_type(CharType::Punctuation), "hello!!!");
+  assert_eq!(sample.trim_end_by_type(CharType::Punctuation), "...hello");
+  assert_eq!("2024report2025".trim_by_type(CharType::DecDigit), "report");
+  let chars = ['-', '_', '*'];
+  assert_eq!("*_-title-_*".trim_by_type(CharType::Chars(&chars)), "title");
+  assert_eq!("!!!".trim_by_type(CharType::Punctuation), "");
+}
+
+#[test]
+fn test_to_numbers_excluding_dates() {
+  let sample = "Meeting on 12/05/2024 cost 300";
+  assert_eq!(sample.to_numbers_excluding_dates::<i32>(), vec![300]);
+  // ISO dates and month/year pairs are skipped too
+  let sample = "Invoice 2024-05-12 for 42.5 units, due 06/2024";
+  assert_eq!(sample.to_numbers_excluding_dates::<f64>(), vec![42.5]);
+  // implausible day and month values are kept as numbers
+  let sample = "Scores 45/60 and 7";
+  assert_eq!(sample.to_numbers_excluding_dates::<i32>(), vec![45, 60, 7]);
+}
+
+#[test]
+fn test_capitalize_sentences() {
+  let sample = "hello world. the iPhone is here! see NASA.  ok?";
+  assert_eq!(sample.capitalize_sentences(), "Hello world. The iPhone is here! See NASA.  Ok?");
+  // only the first letter of each sentence changes
+  assert_eq!("hello. iPhone users rejoice.".capitalize_sentences(), "Hello. IPhone users rejoice.");
+  assert_eq!("\"quoted start.\" next".capitalize_sentences(), "\"Quoted start.\" Next");
+}
+
+#[test]
+fn test_case_composition() {
+  assert!("Abc".has_mixed_case());
+  assert!(!"abc".has_mixed_case());
+  assert!("abc".has_lower() && !"abc".has_upper());
+  assert!(!"123".has_mixed_case());
+  assert!(!"123".has_upper() && !"123".has_lower());
+  assert!("ÉCOLE".has_upper());
+}
+
+#[test]
+fn test_split_trim_numbers() {
+  assert_eq!("  1 , 2 ,3 ".split_trim_numbers::<i32>(","), vec![1, 2, 3]);
+  assert_eq!("1.5; x ;-2.25;;".split_trim_numbers::<f64>(";"), vec![1.5, -2.25]);
+  assert_eq!("4 kg, 5".split_trim_numbers::<u8>(","), vec![5]);
+}
+
+#[test]
+fn test_count_segments() {
+  let path_string = "/var/www/mysite.com/web/uploads/";
+  assert_eq!(path_string.count_segments("/"), path_string.to_segments("/").len());
+  assert_eq!(path_string.count_segments("/"), 5);
+  assert_eq!(path_string.count_parts("/"), path_string.to_parts("/").len());
+  assert_eq!(path_string.count_parts("/"), 7);
+  assert_eq!("".count_segments(","), 0);
+  assert_eq!("".count_parts(","), 1);
+}
+
+#[test]
+fn test_ensure_affixes() {
+  assert_eq!("path".ensure_suffix("/"), "path/");
+  assert_eq!("path/".ensure_suffix("/"), "path/");
+  assert_eq!("path".ensure_prefix("/"), "/path");
+  assert_eq!("/path".ensure_prefix("/"), "/path");
+  assert_eq!("photo.JPG".ensure_suffix_ci(".jpg"), "photo.JPG");
+  assert_eq!("photo.JPG".ensure_suffix(".jpg"), "photo.JPG.jpg");
+  assert_eq!("HTTPS://example.com".ensure_prefix_ci("https://"), "HTTPS://example.com");
+  assert_eq!("example.com".ensure_prefix_ci("https://"), "https://example.com");
+}
+
+#[test]
+fn test_collapse_separators() {
+  let path = "/a//b///c/";
+  assert_eq!(path.collapse_separators("/"), "a/b/c");
+  assert_eq!(path.collapse_separators_with("/", true, true), "/a/b/c/");
+  assert_eq!(path.collapse_separators_with("/", true, false), "/a/b/c");
+  assert_eq!("a::::b::".collapse_separators_with("::", true, true), "a::b::");
+  assert_eq!("///".collapse_separators_with("/", true, false), "/");
+  assert_eq!("///".collapse_separators("/"), "");
+}
+
+#[test]
+fn test_try_match_all_conditional() {
+  let rules = bounds_builder().starts_with_ci("abc", true).or_containing_ci(&["de", "xy"]).as_vec();
+  assert_eq!("abcdef".try_match_all_conditional(&rules), Ok(true));
+  // scalar rules never fail
+  assert_eq!("xyz".try_match_all_conditional(&bounds_builder().contains_cs("a", true).as_vec()), Ok(false));
+  assert_eq!("abcxyz".try_match_all_conditional(&[StringBounds::And(vec![StringBounds::Or(vec![])])]), Ok(false));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_try_match_all_conditional_regex() {
+  let rules = bounds_builder().starts_with_ci("inv", true).matching_regex(r"-\d{4}$", true).as_vec();
+  assert_eq!("INV-2024".try_match_all_conditional(&rules), Ok(true));
+  assert_eq!("INV-24".try_match_all_conditional(&rules), Ok(false));
+  assert!("INV-2024".match_all_conditional(&rules));
+  // an invalid pattern yields an error with the pattern, including in nested rule sets
+  let rules = bounds_builder().starts_with_ci("inv", true).matching_regex(r"-(\d{4}$", true).as_vec();
+  assert_eq!("INV-2024".try_match_all_conditional(&rules), Err(MatchError::InvalidPattern(r"-(\d{4}$".to_string())));
+  let nested = [StringBounds::Or(vec![StringBounds::Contains("x", true, CaseMatchMode::Sensitive), StringBounds::Regex("[a-", true)])];
+  assert_eq!("xyz".try_match_all_conditional(&nested), Err(MatchError::InvalidPattern("[a-".to_string())));
+  // invalid patterns never match in the infallible methods, even in negative rules
+  assert!(!"INV-2024".match_all_conditional(&rules));
+  assert!(!"abc".match_all_conditional(&bounds_builder().matching_regex("[a-", false).as_vec()));
+  assert!("abc".match_all_conditional(&bounds_builder().matching_regex(r"\d", false).as_vec()));
+}
+
+#[test]
+fn test_to_head_n() {
+  assert_eq!("a-b-c-d".to_head_n("-", 2), (vec!["a".to_string(), "b".to_string()], "c-d".to_string()));
+  let (date, time) = "2024-05-12T10:00".to_head_tail("T");
+  assert_eq!(date.to_head_n("-", 1), (vec!["2024".to_string()], "05-12".to_string()));
+  assert_eq!(time, "10:00");
+  // fewer separators than requested fields
+  assert_eq!("a-b".to_head_n("-", 3), (vec!["a".to_string(), "b".to_string()], "".to_string()));
+  assert_eq!("a-b".to_head_n("-", 0), (vec![], "a-b".to_string()));
+}
+
+#[test]
+fn test_ascii_insensitive_mode() {
+  let rules = bounds_builder().starting_with_ascii_ci("abc").as_vec();
+  assert!("ABCdef".match_all_conditional(&rules));
+  let rules = bounds_builder().is_ascii_ci("é").as_vec();
+  assert!(!"É".match_all_conditional(&rules));
+  // the Unicode-aware mode folds non-ASCII letters
+  assert!("É".match_all_conditional(&bounds_builder().is_ci("é").as_vec()));
+  let rules = bounds_builder().containing_ascii_ci("CAFÉ").ending_with_ascii_ci(".TXT").as_vec();
+  assert!("my-cafÉ-menu.txt".match_all_conditional(&rules));
+  assert!(!"my-café-menu.txt".match_all_conditional(&rules));
+  let word_rule = [StringBounds::ContainsWord("Cat", true, CaseMatchMode::AsciiInsensitive)];
+  assert!("the CAT sat".match_all_conditional(&word_rule));
+  assert!(!"category".match_all_conditional(&word_rule));
+}
+
+#[test]
+fn test_to_numbers_trailing_sign() {
+  assert_eq!("balance 5.00-".to_numbers_trailing_sign::<f64>(), vec![-5.0]);
+  assert_eq!("debit 1,250.50-, credit 300".to_numbers_trailing_sign::<f64>(), vec![-1250.5, 300.0]);
+  // a minus followed by digits or letters is not a trailing sign
+  assert_eq!("a 5-year plan".to_numbers_trailing_sign::<i32>(), vec![5]);
+  assert_eq!("pages 5-10".to_numbers_trailing_sign::<i32>(), "pages 5-10".to_numbers::<i32>());
+}
+
+#[test]
+fn test_wrap_each_word() {
+  assert_eq!("red green blue".wrap_each_word('\''), "'red' 'green' 'blue'");
+  assert_eq!("red,  green and blue!".wrap_each_word('('), "(red) (green) (and) (blue)");
+  assert_eq!("".wrap_each_word('"'), "");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_to_segments_regex() {
+  assert_eq!("a,  b ,c".to_segments_regex(r"[,\s]+"), vec!["a", "b", "c"]);
+  assert_eq!(", a,b,".to_segments_regex(r"[,\s]+"), vec!["a", "b"]);
+  // invalid patterns yield the whole string or an error
+  assert_eq!("a,b".to_segments_regex("[,"), vec!["a,b"]);
+  assert!("a,b".try_to_segments_regex("[,").is_err());
+}
+
+#[test]
+fn test_to_number_pairs() {
+  assert_eq!("(3, 4) (5, 6)".to_number_pairs::<i32>(), vec![(3, 4), (5, 6)]);
+  assert_eq!("lat 51.5, lon -0.12".to_number_pairs::<f64>(), vec![(51.5, -0.12)]);
+  // the unpaired trailing number is dropped
+  assert_eq!("1 2 3".to_number_pairs::<u8>(), vec![(1, 2)]);
+  assert_eq!("x1 y2 z3, x4 y5".to_number_triples::<i32>(), vec![(1, 2, 3)]);
+}
+
+#[test]
+fn test_strip_emoji() {
+  assert_eq!("nice 👍🏽 job".strip_emoji(), "nice  job");
+  // joiners and variation selectors from ZWJ sequences are removed too
+  assert_eq!("family: 👨‍👩‍👧 ❤️!".strip_emoji(), "family:  !");
+  assert!("nice 👍🏽 job".has_emoji());
+  assert!(!"plain text © 2024".has_emoji());
+  assert_eq!("go 🇫🇷".strip_by_type(CharType::Emoji), "go ");
+}
+
+#[test]
+fn test_to_key() {
+  assert_eq!("  Café   Noir ".to_key(), "cafe noir");
+  assert_eq!("  Café   Noir ".to_key(), "cafe noir".to_key());
+  assert_eq!("STRASSE\tam\nSee".to_key(), "Straße am See".to_key());
+}
+
+#[test]
+fn test_take_until_after() {
+  let sample = "key: value";
+  assert_eq!(sample.take_until(": "), "key");
+  assert_eq!(sample.take_after(": "), "value");
+  assert_eq!(sample.take_until("="), "key: value");
+  assert_eq!(sample.take_after("="), "");
+  let sample = "Name AND Surname";
+  assert_eq!(sample.take_until_ci(" and "), "Name");
+  assert_eq!(sample.take_after_ci(" and "), "Surname");
+  assert_eq!("Straße".take_after_ci("SS"), "");
+  assert_eq!("CAFÉ latte".take_after_ci("café "), "latte");
+}
+
+#[test]
+fn test_remove_all() {
+  assert_eq!("aXbXXc".remove_all("X"), "abc");
+  assert_eq!("zero\u{200b}width\u{200b}".remove_all("\u{200b}"), "zerowidth");
+  assert_eq!("Test one, TEST two, test three".remove_all_ci("test "), "one, two, three");
+  assert_eq!("abc".remove_all_ci(""), "abc");
+}
+
+#[test]
+fn test_split_fixed_widths() {
+  assert_eq!("ABC12XY".split_fixed_widths(&[3, 2]), vec!["ABC", "12", "XY"]);
+  // widths are counted in characters
+  assert_eq!("Zoë  0042Köln".split_fixed_widths_trimmed(&[5, 4]), vec!["Zoë", "0042", "Köln"]);
+  assert_eq!("Zoë  0042".split_fixed_widths(&[5, 4, 6]), vec!["Zoë  ", "0042"]);
+  assert_eq!("AB".split_fixed_widths(&[3, 2]), vec!["AB"]);
+}
+
+#[test]
+fn test_extract_delimited_tokens() {
+  assert_eq!("{{a}}{{b}}".extract_delimited_tokens("{{", "}}"), vec!["a", "b"]);
+  assert_eq!("Hi {{name}}, your {{item}} ships {{c".extract_delimited_tokens("{{", "}}"), vec!["name", "item"]);
+  // an unmatched opening delimiter before a complete pair is skipped
+  assert_eq!("{{x {{y}} z}}".extract_delimited_tokens("{{", "}}"), vec!["y"]);
+  assert!("no tokens".extract_delimited_tokens("{{", "}}").is_empty());
+}
+
+#[test]
+fn test_to_numbers_auto() {
+  let sample = "Price 1.500 reduced by 12,50";
+  // deduced per number, the ambiguous 1.500 is read as a decimal
+  assert_eq!(sample.to_numbers::<f64>(), vec![1.5, 12.5]);
+  assert_eq!(sample.to_numbers_auto::<f64>(), vec![1500.0, 12.5]);
+  // a number with both separators decides the format
+  assert_eq!("1,000 units at 1,234.50".to_numbers_auto::<f64>(), vec![1000.0, 1234.5]);
+  assert_eq!("12 and 7".to_numbers_auto::<i32>(), vec![12, 7]);
+}
+
+#[test]
+fn test_comment_block() {
+  let snippet = "let a = 1;\nlet b = 2;";
+  assert_eq!(snippet.prefix_lines("> "), "> let a = 1;\n> let b = 2;");
+  assert_eq!(snippet.comment_block(CommentStyle::DoubleSlash), "// let a = 1;\n// let b = 2;");
+  assert_eq!("a = 1\r\n\r\nb = 2\r\n".comment_block(CommentStyle::Hash), "# a = 1\r\n#\r\n# b = 2\r\n");
+  assert_eq!(snippet.comment_block(CommentStyle::Block), "/*\nlet a = 1;\nlet b = 2;\n*/");
+  assert_eq!("x */ y".comment_block(CommentStyle::Block), "/*\nx * / y\n*/");
+}
+
+#[test]
+fn test_find_all_ci() {
+  let expected = vec![(0, "Cat".to_string()), (4, "cAt".to_string()), (8, "CAT".to_string())];
+  assert_eq!("Cat cAt CAT".find_all_ci("cat"), expected);
+  // matches do not overlap
+  assert_eq!("AAaa".find_all_ci("aa"), vec![(0, "AA".to_string()), (2, "aa".to_string())]);
+  assert_eq!("Café CAFÉ".find_all_ci("café"), vec![(0, "Café".to_string()), (6, "CAFÉ".to_string())]);
+  assert!("abc".find_all_ci("").is_empty());
+}
+
+#[test]
+fn test_try_to_segment() {
+  let path = "/User/maria/Documents";
+  assert_eq!(path.try_to_segment("/", 1), Ok("maria".to_string()));
+  assert_eq!(path.try_to_segment("/", -1), Ok("Documents".to_string()));
+  assert_eq!(path.try_to_segment("/", 3), Err(SegmentError::IndexOutOfRange { index: 3, count: 3 }));
+  assert_eq!(path.try_to_segment("/", -4), Err(SegmentError::IndexOutOfRange { index: -4, count: 3 }));
+  assert_eq!("//".try_to_segment("/", 0), Err(SegmentError::NoSegments));
+}
+
+#[test]
+fn test_to_segments_normalized() {
+  assert_eq!("a ,  b  ,c ".to_segments_normalized(","), vec!["a", "b", "c"]);
+  assert_eq!(" New   York ;  ; Los\tAngeles ".to_segments_normalized(";"), vec!["New York", "Los Angeles"]);
+}
+
+#[test]
+fn test_indices_by_type() {
+  assert_eq!("a1b2c3".indices_by_type(CharType::DecDigit), vec![1, 3, 5]);
+  assert_eq!("a1b2c3".char_indices_by_type(CharType::DecDigit), vec![(1, 1), (3, 3), (5, 5)]);
+  // byte offsets and character positions differ after multibyte characters
+  assert_eq!("é1ü2".indices_by_type(CharType::DecDigit), vec![2, 5]);
+  assert_eq!("é1ü2".char_indices_by_type(CharType::DecDigit), vec![(1, 2), (3, 5)]);
+}
+
+#[test]
+fn test_has_type_rule() {
+  let samples = ["id42", "identity", "ID7", "user9"];
+  let rules = bounds_builder().starting_with_ci("id").containing_type(CharType::DecDigit);
+  assert_eq!(samples.filter_all_rules(&rules), vec!["id42", "ID7"]);
+  let rules = bounds_builder().starting_with_ci("id").not_containing_type(CharType::DecDigit);
+  assert_eq!(samples.filter_all_rules(&rules), vec!["identity"]);
+  assert!("abc1".match_all_conditional(&[StringBounds::HasType(CharType::DecDigit, true)]));
+}
+
+#[test]
+fn test_repeat_to_length() {
+  assert_eq!("ab".repeat_to_length(5), "ababa");
+  assert_eq!("----|".repeat_to_length(10), "----|----|");
+  assert_eq!("é-".repeat_to_length(3), "é-é");
+  assert_eq!("".repeat_to_length(4), "");
+  assert_eq!("abc".repeat_to_length(0), "");
+}
+
+#[test]
+fn test_strip_matching_quotes() {
+  assert_eq!(r#""hi""#.strip_matching_quotes(), "hi");
+  assert_eq!("'hi'".strip_matching_quotes(), "hi");
+  assert_eq!(r#""hi'"#.strip_matching_quotes(), r#""hi'"#);
+  assert_eq!("\"".strip_matching_quotes(), "\"");
+  assert_eq!("`cmd`".strip_matching_quotes(), "`cmd`");
+  assert_eq!("`cmd`".strip_matching_quotes_of(&['"', '\'', '`']), "cmd");
+  assert_eq!(r#""say ""hi""""#.strip_matching_quotes_doubled(), r#"say "hi""#);
+  assert_eq!("'it''s'".strip_matching_quotes_doubled(), "it's");
+  assert_eq!(r#"no ""quotes"""#.strip_matching_quotes_doubled(), r#"no ""quotes"""#);
+}
+
+#[test]
+fn test_count_conditional() {
+  let file_names = [
+    "edited-img-Nepal-Feb-2003.psd",
+    "image-Thailand-Mar-2003.jpg",
+    "photo_Nepal_Jan-2005.jpg",
+    "image-India-Mar-2003.jpg",
+    "pic_nepal_Dec-2004.png"
+  ];
+  let rules = bounds_builder()
+    .containing_ci("nepal")
+    .not_ending_with_ci(".psd");
+  assert_eq!(file_names.count_all_rules(&rules), 2);
+  assert_eq!(file_names.to_strings().count_all_conditional(&rules.as_vec()), 2);
+  let rules = bounds_builder()
+    .ending_with_ci(".psd")
+    .ending_with_ci(".png");
+  assert_eq!(file_names.count_any_rules(&rules), 2);
+  assert_eq!(file_names.to_strings().count_any_conditional(&rules.as_vec()), 2);
+}
+
+#[test]
+fn test_to_first_number_with_format() {
+  let swiss = NumberFormat::new('.', Some('\''));
+  assert_eq!("CHF 1'234.50 incl. VAT".to_first_number_with_format::<f64>(&swiss), Some(1234.5));
+  assert_eq!("Total: 1.234,50 €".to_first_number_with_format::<f64>(&NumberFormat::euro()), Some(1234.5));
+  assert_eq!("no numbers".to_first_number_with_format::<f64>(&swiss), None);
+}
+
+#[test]
+fn test_try_enclose() {
+  assert_eq!("plain".try_enclose('"', '"'), Ok(r#""plain""#.to_string()));
+  assert_eq!("a [b] c".try_enclose('[', ']'), Err(EncloseError::UnescapedEnd { end: ']', index: 4 }));
+  let content = r#"say "hi""#;
+  assert_eq!(content.try_enclose('"', '"'), Err(EncloseError::UnescapedEnd { end: '"', index: 4 }));
+  // escaping explicitly avoids the error
+  assert_eq!(content.enclose_safe('"', '"'), r#""say \"hi\"""#);
+  // closing characters escaped with a backslash are accepted
+  let escaped_content = r#"say \"hi\""#;
+  assert_eq!(escaped_content.try_enclose('"', '"'), Ok(r#""say \"hi\"""#.to_string()));
+  // an escaped backslash does not escape the following closing character
+  assert_eq!(r#"C:\\" end"#.try_enclose('"', '"'), Err(EncloseError::UnescapedEnd { end: '"', index: 4 }));
+}
+
+#[test]
+fn test_split_camel_case() {
+  assert_eq!("getHTTPResponseCode".split_camel_case(), vec!["get", "HTTP", "Response", "Code"]);
+  assert_eq!("parseURL2Json".split_camel_case(), vec!["parse", "URL2", "Json"]);
+  assert_eq!("XMLHttpRequest".split_camel_case(), vec!["XML", "Http", "Request"]);
+  assert_eq!("base64Encode_value".split_camel_case(), vec!["base64", "Encode", "value"]);
+  assert_eq!("ID".split_camel_case(), vec!["ID"]);
+  assert!("".split_camel_case().is_empty());
+}